@@ -0,0 +1,113 @@
+#[path = "../utilities.rs"]
+mod utilities;
+use crate::utilities::{write_to_json, OperatorType};
+
+// The boolean layer implementation of the sha256_bool example, used as a baseline. The tests of
+// the example modules are not run by the bench harness, hence the unused imports.
+#[allow(dead_code, unused_imports)]
+#[path = "../../examples/sha256_bool/boolean_ops.rs"]
+mod boolean_ops;
+#[allow(dead_code, unused_imports)]
+#[path = "../../examples/sha256_bool/padding.rs"]
+mod padding;
+#[allow(dead_code, unused_imports)]
+#[path = "../../examples/sha256_bool/sha256_function.rs"]
+mod sha256_function;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tfhe::boolean::keycache::KEY_CACHE as BOOLEAN_KEY_CACHE;
+use tfhe::boolean::parameters::DEFAULT_PARAMETERS;
+use tfhe::integer::keycache::KEY_CACHE;
+use tfhe::integer::IntegerKeyKind;
+use tfhe::keycache::NamedParam;
+use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+use tfhe::shortint::PBSParameters;
+use tfhe::strings::sha256::{sha256, sha256_radix, Adder};
+use tfhe::strings::{ClientKey, ServerKey};
+
+criterion_group!(sha256_benches, bench_sha256);
+
+criterion_main!(sha256_benches);
+
+/// Compares the SHA-256 computed with a boolean circuit to the one computed on radix words,
+/// on a string filling a single chunk.
+///
+/// The boolean layer implementation of the `sha256_bool` example is measured as a baseline. It
+/// hashes the same string, but its bits are padded and encrypted by the client, so it neither
+/// extracts the bits from the characters nor hides the length of the string.
+fn bench_sha256(c: &mut Criterion) {
+    let bench_name = "sha256";
+    let mut bench_group = c.benchmark_group(bench_name);
+    bench_group
+        .sample_size(10)
+        .measurement_time(std::time::Duration::from_secs(60));
+
+    let param: PBSParameters = PARAM_MESSAGE_2_CARRY_2_KS_PBS.into();
+    let param_name = param.name();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let (cks, sks) = (ClientKey::from(cks), ServerKey::new(sks));
+
+    // 48 characters with the padding, the padded message fits in a single chunk
+    let ct = cks.encrypt_ascii("The quick brown fox jumps over", Some(18));
+
+    let cases = [
+        ("boolean_brent_kung", Some(Adder::BrentKung)),
+        ("boolean_ladner_fischer", Some(Adder::LadnerFischer)),
+        ("radix", None),
+    ];
+
+    for (display_name, adder) in cases {
+        let bench_id = format!("{bench_name}::{display_name}::{param_name}");
+        bench_group.bench_function(&bench_id, |b| {
+            b.iter(|| match adder {
+                Some(adder) => black_box(sha256(&sks, &ct, adder)),
+                None => black_box(sha256_radix(&sks, &ct)),
+            })
+        });
+
+        write_to_json::<u64, _>(
+            &bench_id,
+            param,
+            param.name(),
+            display_name,
+            &OperatorType::Atomic,
+            8 * ct.len() as u32,
+            vec![param.message_modulus().0.ilog2(); sks.num_blocks_per_char() * ct.len()],
+        );
+    }
+
+    let keys = BOOLEAN_KEY_CACHE.get_from_param(DEFAULT_PARAMETERS);
+    let (boolean_cks, boolean_sks) = (keys.client_key(), keys.server_key());
+    let padded_input = padding::pad_sha256_input("The quick brown fox jumps over")
+        .into_iter()
+        .map(|bit| boolean_cks.encrypt(bit))
+        .collect::<Vec<_>>();
+
+    for (display_name, ladner_fischer) in [
+        ("boolean_layer_brent_kung", false),
+        ("boolean_layer_ladner_fischer", true),
+    ] {
+        let bench_id = format!("{bench_name}::{display_name}::DEFAULT_PARAMETERS");
+        bench_group.bench_function(&bench_id, |b| {
+            b.iter(|| {
+                black_box(sha256_function::sha256_fhe(
+                    padded_input.clone(),
+                    ladner_fischer,
+                    boolean_sks,
+                ))
+            })
+        });
+
+        write_to_json::<u32, _>(
+            &bench_id,
+            DEFAULT_PARAMETERS,
+            "DEFAULT_PARAMETERS",
+            display_name,
+            &OperatorType::Atomic,
+            padded_input.len() as u32,
+            vec![1; padded_input.len()],
+        );
+    }
+
+    bench_group.finish()
+}
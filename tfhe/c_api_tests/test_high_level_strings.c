@@ -0,0 +1,123 @@
+#include <tfhe.h>
+
+#include <assert.h>
+#include <inttypes.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+int encrypt_decrypt_test(const ClientKey *client_key) {
+  int ok;
+  FheAsciiString *str = NULL;
+  FheAsciiString *deserialized = NULL;
+  char *clear = NULL;
+  Buffer buffer = {.pointer = NULL, .length = 0};
+
+  ok = fhe_ascii_string_try_encrypt_with_client_key("tfhe-rs", 2, client_key, &str);
+  assert(ok == 0);
+
+  ok = fhe_ascii_string_serialize(str, &buffer);
+  assert(ok == 0);
+
+  BufferView view = {.pointer = buffer.pointer, .length = buffer.length};
+  ok = fhe_ascii_string_deserialize(view, &deserialized);
+  assert(ok == 0);
+
+  ok = fhe_ascii_string_decrypt(deserialized, client_key, &clear);
+  assert(ok == 0);
+
+  assert(strcmp(clear, "tfhe-rs") == 0);
+
+  // Non ASCII strings are rejected
+  FheAsciiString *invalid = NULL;
+  ok = fhe_ascii_string_try_encrypt_with_client_key("\xc3\xa9", 0, client_key, &invalid);
+  assert(ok != 0);
+
+  fhe_ascii_string_destroy_decrypted(clear);
+  destroy_buffer(&buffer);
+  fhe_ascii_string_destroy(str);
+  fhe_ascii_string_destroy(deserialized);
+
+  return 0;
+}
+
+int predicates_test(const ClientKey *client_key) {
+  int ok;
+  FheAsciiString *str = NULL;
+  FheAsciiString *pattern = NULL;
+  FheAsciiString *prefix = NULL;
+  FheBool *result = NULL;
+  FheUint16 *index = NULL;
+  bool clear;
+  uint16_t clear_index;
+
+  ok = fhe_ascii_string_try_encrypt_with_client_key("abcab", 1, client_key, &str);
+  assert(ok == 0);
+
+  ok = fhe_ascii_string_try_encrypt_with_client_key("ca", 0, client_key, &pattern);
+  assert(ok == 0);
+
+  ok = fhe_ascii_string_try_encrypt_trivial("ab", &prefix);
+  assert(ok == 0);
+
+  ok = fhe_ascii_string_eq(str, pattern, &result);
+  assert(ok == 0);
+  ok = fhe_bool_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+  assert(clear == false);
+  fhe_bool_destroy(result);
+
+  ok = fhe_ascii_string_contains(str, pattern, &result);
+  assert(ok == 0);
+  ok = fhe_bool_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+  assert(clear == true);
+  fhe_bool_destroy(result);
+
+  ok = fhe_ascii_string_starts_with(str, prefix, &result);
+  assert(ok == 0);
+  ok = fhe_bool_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+  assert(clear == true);
+  fhe_bool_destroy(result);
+
+  ok = fhe_ascii_string_find(str, pattern, &index, &result);
+  assert(ok == 0);
+  ok = fhe_bool_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+  assert(clear == true);
+  ok = fhe_uint16_decrypt(index, client_key, &clear_index);
+  assert(ok == 0);
+  assert(clear_index == 2);
+  fhe_bool_destroy(result);
+  fhe_uint16_destroy(index);
+
+  fhe_ascii_string_destroy(str);
+  fhe_ascii_string_destroy(pattern);
+  fhe_ascii_string_destroy(prefix);
+
+  return 0;
+}
+
+int main(void) {
+  ConfigBuilder *builder;
+  Config *config;
+
+  config_builder_default(&builder);
+  config_builder_build(builder, &config);
+
+  ClientKey *client_key = NULL;
+  ServerKey *server_key = NULL;
+
+  generate_keys(config, &client_key, &server_key);
+
+  set_server_key(server_key);
+
+  encrypt_decrypt_test(client_key);
+  predicates_test(client_key);
+
+  client_key_destroy(client_key);
+  server_key_destroy(server_key);
+
+  return EXIT_SUCCESS;
+}
@@ -1,17 +1,13 @@
-use crate::execution::{Executed, Execution, LazyExecution};
-use crate::parser::{parse, RegExpr};
+use crate::execution::{Executed, ExecutedResult, Execution, LazyExecution};
+use crate::parser::RegExpr;
 use std::rc::Rc;
-use tfhe::integer::{RadixCiphertext, ServerKey};
-
-pub fn has_match(
-    sk: &ServerKey,
-    content: &[RadixCiphertext],
-    pattern: &str,
-) -> Result<RadixCiphertext, Box<dyn std::error::Error>> {
-    let re = parse(pattern)?;
+use tfhe::integer::{IntegerCiphertext, RadixCiphertext, ServerKey};
 
+/// Returns an encryption of 1 if the regular expression `re` matches somewhere in `content`,
+/// and of 0 otherwise.
+pub fn has_match(sk: &ServerKey, re: &RegExpr, content: &[RadixCiphertext]) -> RadixCiphertext {
     let branches: Vec<LazyExecution> = (0..content.len())
-        .flat_map(|i| build_branches(content, &re, i))
+        .flat_map(|i| build_branches(content, re, i))
         .map(|(lazy_branch_res, _)| lazy_branch_res)
         .collect();
 
@@ -36,7 +32,79 @@ pub fn has_match(
         exec.ct_operations_count(),
         exec.cache_hits(),
     );
-    Ok(res)
+    res
+}
+
+/// Counts the non-overlapping matches of the regular expression `re` in `content`.
+///
+/// Matches are considered from left to right, each one starting at a position of `content` and
+/// spanning its longest possible extent; a match is only counted if it does not start inside a
+/// previously counted one. Empty matches are not counted, so a pattern that can only match the
+/// empty string (e.g. `/a?/` on `"bb"`) has no match. The per-position match flags are then added
+/// up with `sum_ciphertexts_parallelized`.
+pub fn count_matches(sk: &ServerKey, re: &RegExpr, content: &[RadixCiphertext]) -> RadixCiphertext {
+    let mut exec = Execution::new(sk.clone());
+
+    // blocked[k] is true when content position k is covered by an already counted match
+    let mut blocked: Vec<ExecutedResult> = vec![exec.ct_false(); content.len()];
+    let mut flags = Vec::with_capacity(content.len());
+
+    for c_pos in 0..content.len() {
+        let mut match_at = exec.ct_false();
+        // ends_after[k] is true when a match starting at c_pos covers position c_pos + 1 + k
+        let mut ends_after = vec![exec.ct_false(); content.len() - c_pos - 1];
+
+        for (branch, branch_c_pos) in build_branches(content, re, c_pos) {
+            if branch_c_pos == c_pos {
+                // Empty match
+                continue;
+            }
+            let branch_res = branch(&mut exec);
+            for covered in ends_after
+                .iter_mut()
+                .take(branch_c_pos.saturating_sub(c_pos + 1))
+            {
+                *covered = exec.ct_or(covered.clone(), branch_res.clone());
+            }
+            match_at = exec.ct_or(match_at, branch_res);
+        }
+
+        let not_blocked = exec.ct_not(blocked[c_pos].clone());
+        let counted = exec.ct_and(match_at, not_blocked);
+
+        for (k, covered) in ends_after.into_iter().enumerate() {
+            let newly_blocked = exec.ct_and(counted.clone(), covered);
+            let k = c_pos + 1 + k;
+            blocked[k] = exec.ct_or(blocked[k].clone(), newly_blocked);
+        }
+
+        flags.push(counted.0);
+    }
+    info!(
+        "{} ciphertext operations, {} cache hits",
+        exec.ct_operations_count(),
+        exec.cache_hits(),
+    );
+
+    // The flags are encrypted on enough blocks to hold a single character, make sure the
+    // count can go up to content.len() without wrapping around
+    let msg_bits = sk.message_modulus().0.ilog2() as usize;
+    let count_bits = (usize::BITS - content.len().leading_zeros()) as usize;
+    let count_blocks = (count_bits + msg_bits - 1) / msg_bits;
+    let flags = flags
+        .into_iter()
+        .map(|flag| {
+            let num_blocks = flag.blocks().len();
+            if count_blocks > num_blocks {
+                sk.extend_radix_with_trivial_zero_blocks_msb(&flag, count_blocks - num_blocks)
+            } else {
+                flag
+            }
+        })
+        .collect::<Vec<_>>();
+
+    sk.sum_ciphertexts_parallelized(flags.iter())
+        .unwrap_or_else(|| exec.ct_false().0)
 }
 
 fn build_branches(
@@ -212,10 +280,11 @@ fn build_branches(
 
 #[cfg(test)]
 mod tests {
-    use crate::engine::has_match;
+    use crate::engine::{count_matches, has_match};
     use test_case::test_case;
 
     use crate::ciphertext::{encrypt_str, gen_keys, StringCiphertext};
+    use crate::parser::parse;
     use lazy_static::lazy_static;
     use tfhe::integer::{RadixClientKey, ServerKey};
 
@@ -255,7 +324,28 @@ mod tests {
     #[test_case(" de", "/^ab|cd|de$/", 0 ; "multiple or nests below ^")]
     fn test_has_match(content: &str, pattern: &str, exp: u64) {
         let ct_content: StringCiphertext = encrypt_str(&KEYS.0, content).unwrap();
-        let ct_res = has_match(&KEYS.1, &ct_content, pattern).unwrap();
+        let re = parse(pattern).unwrap();
+        let ct_res = has_match(&KEYS.1, &re, &ct_content);
+
+        let got = KEYS.0.decrypt(&ct_res);
+        assert_eq!(exp, got);
+    }
+
+    #[test_case("ab", "/ab/", 1)]
+    #[test_case("abab", "/ab/", 2)]
+    #[test_case("aXbab cab", "/ab/", 2)]
+    #[test_case("aaa", "/aa/", 1 ; "matches do not overlap")]
+    #[test_case("aaaa", "/aa/", 2)]
+    #[test_case("aaba", "/a+/", 2 ; "longest match is counted")]
+    #[test_case("abab", "/^ab/", 1)]
+    #[test_case("bc", "/ab/", 0)]
+    #[test_case("Ab aB", "/ab/i", 2)]
+    #[test_case("bab", "/a?/", 1 ; "empty matches are not counted")]
+    #[test_case("bcb", "/a*/", 0 ; "pattern only matching the empty string")]
+    fn test_count_matches(content: &str, pattern: &str, exp: u64) {
+        let ct_content: StringCiphertext = encrypt_str(&KEYS.0, content).unwrap();
+        let re = parse(pattern).unwrap();
+        let ct_res = count_matches(&KEYS.1, &re, &ct_content);
 
         let got = KEYS.0.decrypt(&ct_res);
         assert_eq!(exp, got);
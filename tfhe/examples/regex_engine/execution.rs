@@ -15,7 +15,7 @@ pub(crate) enum Executed {
     LessOrEqual { a: Box<Executed>, b: Box<Executed> },
     Not { a: Box<Executed> },
 }
-type ExecutedResult = (RadixCiphertext, Executed);
+pub(crate) type ExecutedResult = (RadixCiphertext, Executed);
 
 impl Executed {
     pub(crate) fn ct_pos(at: usize) -> Self {
@@ -19,8 +19,16 @@ fn main() {
 
     let (client_key, server_key) = ciphertext::gen_keys();
     let ct_content = ciphertext::encrypt_str(&client_key, content).unwrap();
+    let re = parser::parse(pattern).unwrap();
 
-    let ct_res = engine::has_match(&server_key, &ct_content, pattern).unwrap();
+    if args.get(3).map(String::as_str) == Some("--count") {
+        let ct_res = engine::count_matches(&server_key, &re, &ct_content);
+        let res: u64 = client_key.decrypt(&ct_res);
+        println!("{res} match(es)");
+        return;
+    }
+
+    let ct_res = engine::has_match(&server_key, &re, &ct_content);
     let res: u64 = client_key.decrypt(&ct_res);
     if res == 0 {
         println!("no match");
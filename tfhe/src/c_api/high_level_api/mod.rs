@@ -11,6 +11,8 @@ pub mod i256;
 pub mod integers;
 pub mod keys;
 #[cfg(feature = "integer")]
+pub mod strings;
+#[cfg(feature = "integer")]
 pub mod u128;
 #[cfg(feature = "integer")]
 pub mod u256;
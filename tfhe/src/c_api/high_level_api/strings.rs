@@ -0,0 +1,142 @@
+use crate::c_api::high_level_api::booleans::FheBool;
+use crate::c_api::high_level_api::integers::FheUint16;
+use crate::c_api::high_level_api::keys::ClientKey;
+use crate::c_api::utils::*;
+use crate::high_level_api::prelude::*;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+
+pub struct FheAsciiString(crate::high_level_api::FheAsciiString);
+
+impl_destroy_on_type!(FheAsciiString);
+impl_clone_on_type!(FheAsciiString);
+impl_serialize_deserialize_on_type!(FheAsciiString);
+
+/// Encrypts the null terminated ASCII string `value`, with `padding` extra null characters.
+#[no_mangle]
+pub unsafe extern "C" fn fhe_ascii_string_try_encrypt_with_client_key(
+    value: *const c_char,
+    padding: u32,
+    client_key: *const ClientKey,
+    result: *mut *mut FheAsciiString,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(value).unwrap();
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let value = CStr::from_ptr(value).to_str().unwrap();
+        let client_key = get_ref_checked(client_key).unwrap();
+
+        let inner = crate::high_level_api::FheAsciiString::try_encrypt_with_padding(
+            value,
+            padding,
+            &client_key.0,
+        )
+        .unwrap();
+
+        *result = Box::into_raw(Box::new(FheAsciiString(inner)));
+    })
+}
+
+/// Trivially encrypts the null terminated ASCII string `value`.
+#[no_mangle]
+pub unsafe extern "C" fn fhe_ascii_string_try_encrypt_trivial(
+    value: *const c_char,
+    result: *mut *mut FheAsciiString,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(value).unwrap();
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let value = CStr::from_ptr(value).to_str().unwrap();
+
+        let inner = crate::high_level_api::FheAsciiString::try_encrypt_trivial(value).unwrap();
+
+        *result = Box::into_raw(Box::new(FheAsciiString(inner)));
+    })
+}
+
+/// Decrypts the string into a newly allocated null terminated string.
+///
+/// The result must be freed with [`fhe_ascii_string_destroy_decrypted`].
+#[no_mangle]
+pub unsafe extern "C" fn fhe_ascii_string_decrypt(
+    encrypted_value: *const FheAsciiString,
+    client_key: *const ClientKey,
+    result: *mut *mut c_char,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let client_key = get_ref_checked(client_key).unwrap();
+        let encrypted_value = get_ref_checked(encrypted_value).unwrap();
+
+        let clear: String = encrypted_value.0.decrypt(&client_key.0);
+
+        *result = CString::new(clear).unwrap().into_raw();
+    })
+}
+
+/// Deallocates a string returned by [`fhe_ascii_string_decrypt`].
+///
+/// ptr can be null (no-op in that case)
+#[no_mangle]
+pub unsafe extern "C" fn fhe_ascii_string_destroy_decrypted(ptr: *mut c_char) -> c_int {
+    catch_panic(|| {
+        if !ptr.is_null() {
+            drop(CString::from_raw(ptr));
+        }
+    })
+}
+
+macro_rules! impl_string_predicate {
+    ($($name:ident),* $(,)?) => {
+        $(
+            ::paste::paste! {
+                #[no_mangle]
+                pub unsafe extern "C" fn [<fhe_ascii_string_ $name>](
+                    lhs: *const FheAsciiString,
+                    rhs: *const FheAsciiString,
+                    result: *mut *mut FheBool,
+                ) -> c_int {
+                    catch_panic(|| {
+                        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                        let lhs = get_ref_checked(lhs).unwrap();
+                        let rhs = get_ref_checked(rhs).unwrap();
+
+                        let inner = lhs.0.$name(&rhs.0);
+
+                        *result = Box::into_raw(Box::new(FheBool(inner)));
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_string_predicate!(eq, ne, contains, starts_with);
+
+/// Finds the first occurrence of `pattern` in the string.
+///
+/// `index` is set to the index of the occurrence, which encrypts 0 if `found` encrypts false.
+#[no_mangle]
+pub unsafe extern "C" fn fhe_ascii_string_find(
+    sself: *const FheAsciiString,
+    pattern: *const FheAsciiString,
+    index: *mut *mut FheUint16,
+    found: *mut *mut FheBool,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(index).unwrap();
+        check_ptr_is_non_null_and_aligned(found).unwrap();
+
+        let sself = get_ref_checked(sself).unwrap();
+        let pattern = get_ref_checked(pattern).unwrap();
+
+        let (inner_index, inner_found) = sself.0.find(&pattern.0);
+
+        *index = Box::into_raw(Box::new(FheUint16(inner_index)));
+        *found = Box::into_raw(Box::new(FheBool(inner_found)));
+    })
+}
@@ -4,6 +4,7 @@ expand_pub_use_fhe_type!(
         FheUint256, FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256
     };
 );
+pub use types::{FheIntDyn, FheUintDyn};
 
 pub(in crate::high_level_api) use keys::{
     IntegerClientKey, IntegerCompactPublicKey, IntegerCompressedCompactPublicKey,
@@ -11,6 +12,7 @@ pub(in crate::high_level_api) use keys::{
 };
 
 pub(in crate::high_level_api) use parameters::IntegerId;
+pub(in crate::high_level_api) use types::static_::{FheUint16Id, FheUint32Id, FheUint8Id};
 pub(in crate::high_level_api) use types::GenericInteger;
 
 mod client_key;
@@ -28,6 +30,7 @@ pub mod safe_serialize {
     use super::parameters::IntegerId;
     use super::types::compact::GenericCompactInteger;
     use super::types::compressed::CompressedGenericInteger;
+    use super::types::dynamic::GenericDynInteger;
     use super::types::GenericInteger;
     use crate::conformance::ParameterSetConformant;
     use crate::integer::parameters::RadixCiphertextConformanceParams;
@@ -89,6 +92,28 @@ pub mod safe_serialize {
         )
     }
 
+    pub fn safe_deserialize_conformant_dyn_integer<T>(
+        reader: impl std::io::Read,
+        serialized_size_limit: u64,
+        sk: &ServerKey,
+        num_blocks: usize,
+    ) -> Result<GenericDynInteger<T>, String>
+    where
+        T: ParameterSetConformant<ParameterSet = RadixCiphertextConformanceParams>
+            + DeserializeOwned,
+    {
+        let parameter_set = RadixCiphertextConformanceParams {
+            shortint_params: sk.integer_key.pbs_key().key.conformance_params(),
+            num_blocks_per_integer: num_blocks,
+        };
+
+        crate::safe_deserialization::safe_deserialize_conformant(
+            reader,
+            serialized_size_limit,
+            &parameter_set,
+        )
+    }
+
     pub fn safe_deserialize_conformant_compressed_integer<Id>(
         reader: impl std::io::Read,
         serialized_size_limit: u64,
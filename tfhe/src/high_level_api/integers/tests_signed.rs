@@ -3,7 +3,7 @@ use crate::prelude::*;
 use crate::{
     generate_keys, set_server_key, CompactFheInt32, CompactFheInt32List, CompactPublicKey,
     CompressedFheInt16, Config, ConfigBuilder, FheInt16, FheInt256, FheInt32, FheInt64, FheInt8,
-    FheUint64, FheUint8,
+    FheIntDyn, FheUint64, FheUint8,
 };
 use rand::prelude::*;
 
@@ -620,3 +620,164 @@ fn test_compact_public_key_list(config: Config) {
     let decrypted: i32 = a.decrypt(&client_key);
     assert_eq!(clear_xs[0], decrypted);
 }
+
+#[test]
+fn test_int32_bit_counts() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    for clear in [
+        0i32,
+        -1,
+        i32::MIN,
+        rng.gen_range(i32::MIN..0),
+        rng.gen_range(1..=i32::MAX),
+    ] {
+        let a = FheInt32::encrypt(clear, &client_key);
+
+        let count: u32 = a.count_ones().decrypt(&client_key);
+        assert_eq!(count, clear.count_ones());
+        let count: u32 = a.count_zeros().decrypt(&client_key);
+        assert_eq!(count, clear.count_zeros());
+        let count: u32 = a.leading_zeros().decrypt(&client_key);
+        assert_eq!(count, clear.leading_zeros());
+        let count: u32 = a.trailing_zeros().decrypt(&client_key);
+        assert_eq!(count, clear.trailing_zeros());
+
+        let (log, is_ok) = a.checked_ilog2();
+        assert_eq!(is_ok.decrypt(&client_key), clear > 0);
+        if let Some(expected) = clear.checked_ilog2() {
+            let log: u32 = log.decrypt(&client_key);
+            assert_eq!(log, expected);
+        }
+    }
+}
+
+#[test]
+fn test_int8_overflowing_ops() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    for (clear_a, clear_b) in [(i8::MIN, -1i8), (-3, 5), (rng.gen::<i8>(), rng.gen::<i8>())] {
+        let a = FheInt8::encrypt(clear_a, &client_key);
+        let b = FheInt8::encrypt(clear_b, &client_key);
+
+        let (result, overflowed) = a.overflowing_add(&b);
+        let result: i8 = result.decrypt(&client_key);
+        assert_eq!(
+            (result, overflowed.decrypt(&client_key)),
+            clear_a.overflowing_add(clear_b)
+        );
+
+        let (result, overflowed) = a.overflowing_sub(&b);
+        let result: i8 = result.decrypt(&client_key);
+        assert_eq!(
+            (result, overflowed.decrypt(&client_key)),
+            clear_a.overflowing_sub(clear_b)
+        );
+
+        let (result, overflowed) = a.overflowing_mul(&b);
+        let result: i8 = result.decrypt(&client_key);
+        assert_eq!(
+            (result, overflowed.decrypt(&client_key)),
+            clear_a.overflowing_mul(clear_b)
+        );
+
+        let (result, is_ok) = a.checked_add(&b);
+        assert_eq!(
+            is_ok.decrypt(&client_key),
+            clear_a.checked_add(clear_b).is_some()
+        );
+        if let Some(expected) = clear_a.checked_add(clear_b) {
+            let result: i8 = result.decrypt(&client_key);
+            assert_eq!(result, expected);
+        }
+    }
+}
+
+#[test]
+fn test_int8_saturating_ops() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    for (clear_a, clear_b) in [
+        (i8::MIN, 1i8),
+        (i8::MAX, -1),
+        (rng.gen::<i8>(), rng.gen::<i8>()),
+    ] {
+        let a = FheInt8::encrypt(clear_a, &client_key);
+        let b = FheInt8::encrypt(clear_b, &client_key);
+
+        let result: i8 = a.saturating_add(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_add(clear_b));
+
+        let result: i8 = a.saturating_sub(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_sub(clear_b));
+
+        let result: i8 = a.saturating_mul(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_mul(clear_b));
+    }
+}
+
+#[test]
+fn test_int_dyn() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    // 40 bits with the default parameters
+    let num_blocks = 20;
+    let min = -(1i64 << 39);
+    let max = (1i64 << 39) - 1;
+    let clear_a = rng.gen_range(min / 2..max / 2);
+    let clear_b = rng.gen_range(min / 2..max / 2);
+
+    let a = FheIntDyn::encrypt((clear_a, num_blocks), &client_key);
+    let b = FheIntDyn::encrypt((clear_b, num_blocks), &client_key);
+
+    let c = &a + &b;
+    let decrypted: i64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a + clear_b);
+
+    let c = &a - &b;
+    let decrypted: i64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a - clear_b);
+
+    let c = -&a;
+    let decrypted: i64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, -clear_a);
+
+    let c = &a + 5i64;
+    let decrypted: i64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a + 5);
+
+    let c = a.max(&b);
+    let decrypted: i64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.max(clear_b));
+
+    let is_ge = a.ge(&b);
+    assert_eq!(is_ge.decrypt(&client_key), clear_a >= clear_b);
+
+    // Sign extension when casting to a static type
+    let c: FheInt64 = a.cast_into();
+    let decrypted: i64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a);
+
+    // and back, keeping the number of blocks of the static type
+    let c = FheIntDyn::cast_from(c);
+    assert_eq!(c.num_blocks(), 32);
+    let c = c.cast_to(num_blocks);
+    let decrypted: i64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a);
+}
@@ -5,8 +5,8 @@ use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder, FheUin
 use crate::integer::U256;
 use crate::{
     CompactFheUint32, CompactFheUint32List, CompactPublicKey, CompressedFheUint16,
-    CompressedFheUint256, CompressedPublicKey, Config, FheInt16, FheInt32, FheInt8, FheUint128,
-    FheUint16, FheUint256, FheUint32, FheUint64,
+    CompressedFheUint256, CompressedPublicKey, Config, FheInt16, FheInt32, FheInt8, FheIntDyn,
+    FheUint128, FheUint16, FheUint256, FheUint32, FheUint64, FheUintDyn,
 };
 
 #[test]
@@ -809,3 +809,266 @@ fn test_if_then_else() {
         if clear_a <= clear_b { clear_b } else { clear_a }
     );
 }
+
+#[test]
+fn test_uint32_bit_counts() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    for clear in [0u32, 1, u32::MAX, rng.gen::<u32>()] {
+        let a = FheUint32::encrypt(clear, &client_key);
+
+        let count: u32 = a.count_ones().decrypt(&client_key);
+        assert_eq!(count, clear.count_ones());
+        let count: u32 = a.count_zeros().decrypt(&client_key);
+        assert_eq!(count, clear.count_zeros());
+        let count: u32 = a.leading_zeros().decrypt(&client_key);
+        assert_eq!(count, clear.leading_zeros());
+        let count: u32 = a.trailing_zeros().decrypt(&client_key);
+        assert_eq!(count, clear.trailing_zeros());
+
+        let (log, is_ok) = a.checked_ilog2();
+        assert_eq!(is_ok.decrypt(&client_key), clear > 0);
+        if let Some(expected) = clear.checked_ilog2() {
+            let log: u32 = log.decrypt(&client_key);
+            assert_eq!(log, expected);
+            let log: u32 = a.ilog2().decrypt(&client_key);
+            assert_eq!(log, expected);
+        }
+    }
+}
+
+#[test]
+fn test_uint8_overflowing_ops() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    for (clear_a, clear_b) in [(u8::MAX, 1u8), (3, 5), (rng.gen::<u8>(), rng.gen::<u8>())] {
+        let a = FheUint8::encrypt(clear_a, &client_key);
+        let b = FheUint8::encrypt(clear_b, &client_key);
+
+        let (result, overflowed) = a.overflowing_add(&b);
+        let result: u8 = result.decrypt(&client_key);
+        assert_eq!(
+            (result, overflowed.decrypt(&client_key)),
+            clear_a.overflowing_add(clear_b)
+        );
+
+        let (result, overflowed) = a.overflowing_sub(&b);
+        let result: u8 = result.decrypt(&client_key);
+        assert_eq!(
+            (result, overflowed.decrypt(&client_key)),
+            clear_a.overflowing_sub(clear_b)
+        );
+
+        let (result, overflowed) = a.overflowing_mul(&b);
+        let result: u8 = result.decrypt(&client_key);
+        assert_eq!(
+            (result, overflowed.decrypt(&client_key)),
+            clear_a.overflowing_mul(clear_b)
+        );
+
+        let (result, is_ok) = a.checked_mul(&b);
+        assert_eq!(
+            is_ok.decrypt(&client_key),
+            clear_a.checked_mul(clear_b).is_some()
+        );
+        if let Some(expected) = clear_a.checked_mul(clear_b) {
+            let result: u8 = result.decrypt(&client_key);
+            assert_eq!(result, expected);
+        }
+    }
+}
+
+#[test]
+fn test_uint8_saturating_ops() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    for (clear_a, clear_b) in [(u8::MAX, 2u8), (3, 5), (rng.gen::<u8>(), rng.gen::<u8>())] {
+        let a = FheUint8::encrypt(clear_a, &client_key);
+        let b = FheUint8::encrypt(clear_b, &client_key);
+
+        let result: u8 = a.saturating_add(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_add(clear_b));
+
+        let result: u8 = a.saturating_sub(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_sub(clear_b));
+
+        let result: u8 = a.saturating_mul(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_mul(clear_b));
+    }
+}
+
+#[test]
+fn test_uint_dyn() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    // 24 bits with the default parameters
+    let num_blocks = 12;
+    let modulus = 1u32 << 24;
+    let clear_a = rng.gen::<u32>() % modulus;
+    let clear_b = rng.gen::<u32>() % modulus;
+
+    let a = FheUintDyn::encrypt((clear_a, num_blocks), &client_key);
+    let b = FheUintDyn::encrypt((clear_b, num_blocks), &client_key);
+    assert_eq!(a.num_blocks(), num_blocks);
+
+    let c = &a + &b;
+    let decrypted: u32 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_add(clear_b) % modulus);
+
+    let c = &a - &b;
+    let decrypted: u32 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_sub(clear_b) % modulus);
+
+    let c = &a ^ &b;
+    let decrypted: u32 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a ^ clear_b);
+
+    let c = &a * 3u32;
+    let decrypted: u32 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_mul(3) % modulus);
+
+    let c = &a >> 5u32;
+    let decrypted: u32 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a >> 5);
+
+    let mut c = a.clone();
+    c += &b;
+    let decrypted: u32 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_add(clear_b) % modulus);
+
+    let is_lt = a.lt(&b);
+    assert_eq!(is_lt.decrypt(&client_key), clear_a < clear_b);
+
+    let is_eq = a.eq(clear_a);
+    assert!(is_eq.decrypt(&client_key));
+
+    let trivial = FheUintDyn::encrypt_trivial((clear_b, num_blocks));
+    let decrypted: u32 = trivial.decrypt(&client_key);
+    assert_eq!(decrypted, clear_b);
+}
+
+#[test]
+#[should_panic(expected = "Both operands must have the same number of blocks")]
+fn test_uint_dyn_different_num_blocks() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let a = FheUintDyn::encrypt((1u32, 12), &client_key);
+    let b = FheUintDyn::encrypt((1u32, 20), &client_key);
+
+    let _ = a + b;
+}
+
+#[test]
+fn test_uint_dyn_casting() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    let clear = rng.gen::<u16>();
+
+    // From a static type, the number of blocks is kept
+    let a = FheUint16::encrypt(clear, &client_key);
+    let a = FheUintDyn::cast_from(a);
+    assert_eq!(a.num_blocks(), 8);
+    let da: u16 = a.decrypt(&client_key);
+    assert_eq!(da, clear);
+
+    // Upcasting to 40 bits then downcasting to 12 bits
+    let a = a.cast_to(20);
+    assert_eq!(a.num_blocks(), 20);
+    let da: u64 = a.decrypt(&client_key);
+    assert_eq!(da, clear as u64);
+
+    let a = a.cast_to(6);
+    let da: u16 = a.decrypt(&client_key);
+    assert_eq!(da, clear % (1 << 12));
+
+    // To static types
+    let b: FheUint32 = a.clone().cast_into();
+    let db: u32 = b.decrypt(&client_key);
+    assert_eq!(db, (clear % (1 << 12)) as u32);
+
+    let b: FheUint8 = a.clone().cast_into();
+    let db: u8 = b.decrypt(&client_key);
+    assert_eq!(db, clear as u8);
+
+    // Same number of blocks, different signedness
+    let c = FheIntDyn::cast_from(a);
+    let dc: i16 = c.cast_to(8).decrypt(&client_key);
+    assert_eq!(dc, ((clear << 4) as i16) >> 4);
+}
+
+#[test]
+fn test_uint_dyn_conformance() {
+    use crate::conformance::ParameterSetConformant;
+    use crate::integer::parameters::RadixCiphertextConformanceParams;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+    let config = ConfigBuilder::default().build();
+    let (client_key, _server_key) = generate_keys(config);
+
+    let a = FheUintDyn::encrypt((1u32, 12), &client_key);
+
+    assert!(
+        a.is_conformant(&RadixCiphertextConformanceParams::from_pbs_parameters(
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            12
+        ))
+    );
+    assert!(
+        !a.is_conformant(&RadixCiphertextConformanceParams::from_pbs_parameters(
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            4
+        ))
+    );
+}
+
+#[cfg(feature = "safe-deserialization")]
+#[test]
+fn test_uint_dyn_safe_serialization() {
+    use crate::high_level_api::{safe_deserialize_conformant_dyn_integer, safe_serialize};
+
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+
+    let clear = 1234u32;
+    let a = FheUintDyn::encrypt((clear, 12), &client_key);
+
+    let mut buffer = vec![];
+    safe_serialize(&a, &mut buffer, 1 << 40).unwrap();
+
+    assert!(
+        safe_deserialize_conformant_dyn_integer::<crate::integer::RadixCiphertext>(
+            buffer.as_slice(),
+            1 << 30,
+            &server_key,
+            8,
+        )
+        .is_err()
+    );
+
+    let b: FheUintDyn =
+        safe_deserialize_conformant_dyn_integer(buffer.as_slice(), 1 << 30, &server_key, 12)
+            .unwrap();
+    let decrypted: u32 = b.decrypt(&client_key);
+    assert_eq!(decrypted, clear);
+}
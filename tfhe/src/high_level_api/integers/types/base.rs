@@ -8,7 +8,7 @@ use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::prelude::CastFrom;
 use crate::high_level_api::global_state::WithGlobalKey;
 use crate::high_level_api::integers::parameters::IntegerId;
-use crate::high_level_api::integers::IntegerServerKey;
+use crate::high_level_api::integers::{FheUint32Id, IntegerServerKey};
 use crate::high_level_api::internal_traits::{DecryptionKey, EncryptionKey};
 use crate::high_level_api::keys::CompressedPublicKey;
 use crate::high_level_api::traits::{
@@ -20,7 +20,7 @@ use crate::high_level_api::{ClientKey, PublicKey};
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::ciphertext::{IntegerRadixCiphertext, RadixCiphertext};
 use crate::integer::parameters::RadixCiphertextConformanceParams;
-use crate::integer::{IntegerCiphertext, SignedRadixCiphertext, I256, U256};
+use crate::integer::{BooleanBlock, IntegerCiphertext, SignedRadixCiphertext, I256, U256};
 use crate::named::Named;
 use crate::{CompactPublicKey, FheBool};
 
@@ -112,6 +112,167 @@ where
 
         Self::new(ciphertext, self.id)
     }
+
+    /// Returns the number of ones in the binary representation of `self`.
+    pub fn count_ones(&self) -> crate::FheUint32 {
+        self.bit_count(crate::integer::ServerKey::count_ones_parallelized)
+    }
+
+    /// Returns the number of zeros in the binary representation of `self`.
+    pub fn count_zeros(&self) -> crate::FheUint32 {
+        self.bit_count(crate::integer::ServerKey::count_zeros_parallelized)
+    }
+
+    /// Returns the number of leading zeros in the binary representation of `self`.
+    pub fn leading_zeros(&self) -> crate::FheUint32 {
+        self.bit_count(crate::integer::ServerKey::leading_zeros_parallelized)
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of `self`.
+    pub fn trailing_zeros(&self) -> crate::FheUint32 {
+        self.bit_count(crate::integer::ServerKey::trailing_zeros_parallelized)
+    }
+
+    /// Returns the base 2 logarithm of `self`, rounded down.
+    ///
+    /// The result is meaningless if `self` is not strictly positive, see [Self::checked_ilog2].
+    pub fn ilog2(&self) -> crate::FheUint32 {
+        self.bit_count(crate::integer::ServerKey::ilog2_parallelized)
+    }
+
+    /// Returns the base 2 logarithm of `self` rounded down, and whether `self` is strictly
+    /// positive (in which case the logarithm is meaningful).
+    pub fn checked_ilog2(&self) -> (crate::FheUint32, FheBool) {
+        let (ciphertext, is_positive) =
+            crate::high_level_api::global_state::with_internal_keys(|keys| {
+                let integer_key = keys.integer_key.pbs_key();
+                let (log, is_positive) = integer_key.checked_ilog2_parallelized(&self.ciphertext);
+                (Self::extend_bit_count(integer_key, log), is_positive)
+            });
+
+        (
+            crate::FheUint32::new(ciphertext, FheUint32Id),
+            FheBool::new(is_positive),
+        )
+    }
+
+    /// Adds `self` and `rhs`, and returns whether the addition overflowed.
+    ///
+    /// The result is wrapped around the number of bits of the type, like `+` does.
+    pub fn overflowing_add(&self, rhs: &Self) -> (Self, FheBool) {
+        self.overflowing_op(rhs, crate::integer::ServerKey::overflowing_add_parallelized)
+    }
+
+    /// Subtracts `rhs` from `self`, and returns whether the subtraction overflowed.
+    ///
+    /// The result is wrapped around the number of bits of the type, like `-` does.
+    pub fn overflowing_sub(&self, rhs: &Self) -> (Self, FheBool) {
+        self.overflowing_op(rhs, crate::integer::ServerKey::overflowing_sub_parallelized)
+    }
+
+    /// Multiplies `self` by `rhs`, and returns whether the multiplication overflowed.
+    ///
+    /// The result is wrapped around the number of bits of the type, like `*` does.
+    pub fn overflowing_mul(&self, rhs: &Self) -> (Self, FheBool) {
+        self.overflowing_op(rhs, crate::integer::ServerKey::overflowing_mul_parallelized)
+    }
+
+    /// Adds `self` and `rhs`, and returns whether the addition did not overflow (in which case
+    /// the result is meaningful).
+    pub fn checked_add(&self, rhs: &Self) -> (Self, FheBool) {
+        let (result, overflowed) = self.overflowing_add(rhs);
+        (result, !overflowed)
+    }
+
+    /// Subtracts `rhs` from `self`, and returns whether the subtraction did not overflow (in
+    /// which case the result is meaningful).
+    pub fn checked_sub(&self, rhs: &Self) -> (Self, FheBool) {
+        let (result, overflowed) = self.overflowing_sub(rhs);
+        (result, !overflowed)
+    }
+
+    /// Multiplies `self` by `rhs`, and returns whether the multiplication did not overflow (in
+    /// which case the result is meaningful).
+    pub fn checked_mul(&self, rhs: &Self) -> (Self, FheBool) {
+        let (result, overflowed) = self.overflowing_mul(rhs);
+        (result, !overflowed)
+    }
+
+    /// Adds `self` and `rhs`, saturating at the bounds of the type instead of wrapping around.
+    pub fn saturating_add(&self, rhs: &Self) -> Self {
+        self.saturating_op(rhs, crate::integer::ServerKey::saturating_add_parallelized)
+    }
+
+    /// Subtracts `rhs` from `self`, saturating at the bounds of the type instead of wrapping
+    /// around.
+    pub fn saturating_sub(&self, rhs: &Self) -> Self {
+        self.saturating_op(rhs, crate::integer::ServerKey::saturating_sub_parallelized)
+    }
+
+    /// Multiplies `self` by `rhs`, saturating at the bounds of the type instead of wrapping
+    /// around.
+    pub fn saturating_mul(&self, rhs: &Self) -> Self {
+        self.saturating_op(rhs, crate::integer::ServerKey::saturating_mul_parallelized)
+    }
+
+    /// Computes a saturating operation on `self` and `rhs`.
+    fn saturating_op<F>(&self, rhs: &Self, func: F) -> Self
+    where
+        F: FnOnce(
+            &crate::integer::ServerKey,
+            &Id::InnerCiphertext,
+            &Id::InnerCiphertext,
+        ) -> Id::InnerCiphertext,
+    {
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let integer_key = keys.integer_key.pbs_key();
+            func(integer_key, &self.ciphertext, &rhs.ciphertext)
+        });
+
+        Self::new(ciphertext, self.id)
+    }
+
+    /// Computes an operation returning an overflow flag on `self` and `rhs`.
+    fn overflowing_op<F>(&self, rhs: &Self, func: F) -> (Self, FheBool)
+    where
+        F: FnOnce(
+            &crate::integer::ServerKey,
+            &Id::InnerCiphertext,
+            &Id::InnerCiphertext,
+        ) -> (Id::InnerCiphertext, BooleanBlock),
+    {
+        let (ciphertext, overflowed) =
+            crate::high_level_api::global_state::with_internal_keys(|keys| {
+                let integer_key = keys.integer_key.pbs_key();
+                func(integer_key, &self.ciphertext, &rhs.ciphertext)
+            });
+
+        (Self::new(ciphertext, self.id), FheBool::new(overflowed))
+    }
+
+    /// Computes a count of bits of `self`, and casts it to a `FheUint32`.
+    fn bit_count<F>(&self, func: F) -> crate::FheUint32
+    where
+        F: FnOnce(&crate::integer::ServerKey, &Id::InnerCiphertext) -> RadixCiphertext,
+    {
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let integer_key = keys.integer_key.pbs_key();
+            let count = func(integer_key, &self.ciphertext);
+            Self::extend_bit_count(integer_key, count)
+        });
+
+        crate::FheUint32::new(ciphertext, FheUint32Id)
+    }
+
+    /// Extends a count of bits to the number of blocks of a `FheUint32`.
+    fn extend_bit_count(
+        integer_key: &crate::integer::ServerKey,
+        mut count: RadixCiphertext,
+    ) -> RadixCiphertext {
+        let num_blocks_to_add = FheUint32Id::num_blocks() - count.blocks().len();
+        integer_key.extend_radix_with_trivial_zero_blocks_msb_assign(&mut count, num_blocks_to_add);
+        count
+    }
 }
 
 impl<FromId, IntoId> CastFrom<GenericInteger<FromId>> for GenericInteger<IntoId>
@@ -122,52 +283,11 @@ where
     fn cast_from(input: GenericInteger<FromId>) -> Self {
         crate::high_level_api::global_state::with_internal_keys(|keys| {
             let integer_key = keys.integer_key.pbs_key();
-            let current_num_blocks = FromId::num_blocks();
-            let target_num_blocks = IntoId::num_blocks();
-
-            let blocks = if FromId::InnerCiphertext::IS_SIGNED {
-                if target_num_blocks > current_num_blocks {
-                    let mut ct_as_signed_radix =
-                        SignedRadixCiphertext::from_blocks(input.ciphertext.into_blocks());
-                    let num_blocks_to_add = target_num_blocks - current_num_blocks;
-                    integer_key.extend_radix_with_sign_msb_assign(
-                        &mut ct_as_signed_radix,
-                        num_blocks_to_add,
-                    );
-                    ct_as_signed_radix.blocks
-                } else {
-                    let mut ct_as_unsigned_radix =
-                        RadixCiphertext::from_blocks(input.ciphertext.into_blocks());
-                    let num_blocks_to_remove = current_num_blocks - target_num_blocks;
-                    integer_key.trim_radix_blocks_msb_assign(
-                        &mut ct_as_unsigned_radix,
-                        num_blocks_to_remove,
-                    );
-                    ct_as_unsigned_radix.blocks
-                }
-            } else {
-                let mut ct_as_unsigned_radix =
-                    RadixCiphertext::from_blocks(input.ciphertext.into_blocks());
-                if target_num_blocks > current_num_blocks {
-                    let num_blocks_to_add = target_num_blocks - current_num_blocks;
-                    integer_key.extend_radix_with_trivial_zero_blocks_msb_assign(
-                        &mut ct_as_unsigned_radix,
-                        num_blocks_to_add,
-                    );
-                } else {
-                    let num_blocks_to_remove = current_num_blocks - target_num_blocks;
-                    integer_key.trim_radix_blocks_msb_assign(
-                        &mut ct_as_unsigned_radix,
-                        num_blocks_to_remove,
-                    );
-                }
-                ct_as_unsigned_radix.blocks
-            };
-
-            assert_eq!(
-                blocks.len(),
+            let blocks = cast_blocks(
+                integer_key,
+                input.ciphertext.into_blocks(),
+                FromId::InnerCiphertext::IS_SIGNED,
                 IntoId::num_blocks(),
-                "internal error, wrong number of blocks after casting"
             );
             let new_ciphertext = IntoId::InnerCiphertext::from_blocks(blocks);
             Self::new(new_ciphertext, IntoId::default())
@@ -175,6 +295,54 @@ where
     }
 }
 
+/// Casts the blocks of an integer to `target_num_blocks` blocks, sign extending them if
+/// `is_signed` and zero extending them otherwise.
+pub(super) fn cast_blocks(
+    integer_key: &crate::integer::ServerKey,
+    blocks: Vec<crate::shortint::Ciphertext>,
+    is_signed: bool,
+    target_num_blocks: usize,
+) -> Vec<crate::shortint::Ciphertext> {
+    let current_num_blocks = blocks.len();
+
+    let blocks = if is_signed {
+        if target_num_blocks > current_num_blocks {
+            let mut ct_as_signed_radix = SignedRadixCiphertext::from_blocks(blocks);
+            let num_blocks_to_add = target_num_blocks - current_num_blocks;
+            integer_key
+                .extend_radix_with_sign_msb_assign(&mut ct_as_signed_radix, num_blocks_to_add);
+            ct_as_signed_radix.blocks
+        } else {
+            let mut ct_as_unsigned_radix = RadixCiphertext::from_blocks(blocks);
+            let num_blocks_to_remove = current_num_blocks - target_num_blocks;
+            integer_key
+                .trim_radix_blocks_msb_assign(&mut ct_as_unsigned_radix, num_blocks_to_remove);
+            ct_as_unsigned_radix.blocks
+        }
+    } else {
+        let mut ct_as_unsigned_radix = RadixCiphertext::from_blocks(blocks);
+        if target_num_blocks > current_num_blocks {
+            let num_blocks_to_add = target_num_blocks - current_num_blocks;
+            integer_key.extend_radix_with_trivial_zero_blocks_msb_assign(
+                &mut ct_as_unsigned_radix,
+                num_blocks_to_add,
+            );
+        } else {
+            let num_blocks_to_remove = current_num_blocks - target_num_blocks;
+            integer_key
+                .trim_radix_blocks_msb_assign(&mut ct_as_unsigned_radix, num_blocks_to_remove);
+        }
+        ct_as_unsigned_radix.blocks
+    };
+
+    assert_eq!(
+        blocks.len(),
+        target_num_blocks,
+        "internal error, wrong number of blocks after casting"
+    );
+    blocks
+}
+
 impl<Id> CastFrom<FheBool> for GenericInteger<Id>
 where
     Id: IntegerId,
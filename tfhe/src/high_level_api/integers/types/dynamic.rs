@@ -0,0 +1,987 @@
+use std::borrow::Borrow;
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+use super::base::{cast_blocks, GenericInteger};
+use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::prelude::CastFrom;
+use crate::high_level_api::integers::parameters::IntegerId;
+use crate::high_level_api::internal_traits::{DecryptionKey, EncryptionKey};
+use crate::high_level_api::keys::CompressedPublicKey;
+use crate::high_level_api::traits::{
+    DivRem, FheDecrypt, FheEq, FheMax, FheMin, FheOrd, FheTrivialEncrypt, FheTryEncrypt,
+    FheTryTrivialEncrypt, RotateLeft, RotateLeftAssign, RotateRight, RotateRightAssign,
+};
+use crate::high_level_api::{ClientKey, PublicKey};
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::ciphertext::{IntegerRadixCiphertext, RadixCiphertext};
+use crate::integer::parameters::RadixCiphertextConformanceParams;
+use crate::integer::{IntegerCiphertext, SignedRadixCiphertext, I256, U256};
+use crate::named::Named;
+use crate::{CompactPublicKey, FheBool};
+
+/// A FHE integer whose number of blocks is chosen at runtime.
+///
+/// Unlike [GenericInteger], whose number of blocks is fixed by its type, this type carries its
+/// number of blocks in its value, which is given at encryption time along with the clear value:
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUintDyn};
+///
+/// let config = ConfigBuilder::default().build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// // With the default parameters, each block holds 2 bits, so 12 blocks make a 24 bits integer
+/// let a = FheUintDyn::encrypt((10_000_000u32, 12), &client_key);
+/// let b = FheUintDyn::encrypt((1_000_000u32, 12), &client_key);
+///
+/// let c = &a + &b;
+/// let decrypted: u32 = c.decrypt(&client_key);
+/// assert_eq!(decrypted, 11_000_000);
+/// ```
+///
+/// The operators require both operands to have the same number of blocks, [Self::cast_to] and
+/// the casts from and to the static types can be used to change it.
+///
+/// You will need to use one of this type specialization ([FheUintDyn], [FheIntDyn]).
+///
+/// [FheUintDyn]: crate::high_level_api::FheUintDyn
+/// [FheIntDyn]: crate::high_level_api::FheIntDyn
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct GenericDynInteger<T> {
+    pub(in crate::high_level_api) ciphertext: T,
+}
+
+/// An unsigned integer type whose number of blocks is chosen at runtime
+#[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+pub type FheUintDyn = GenericDynInteger<RadixCiphertext>;
+
+/// A signed integer type whose number of blocks is chosen at runtime
+#[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+pub type FheIntDyn = GenericDynInteger<SignedRadixCiphertext>;
+
+impl<T> ParameterSetConformant for GenericDynInteger<T>
+where
+    T: ParameterSetConformant<ParameterSet = RadixCiphertextConformanceParams>,
+{
+    type ParameterSet = RadixCiphertextConformanceParams;
+    fn is_conformant(&self, params: &RadixCiphertextConformanceParams) -> bool {
+        self.ciphertext.is_conformant(params)
+    }
+}
+
+impl<T> Named for GenericDynInteger<T> {
+    const NAME: &'static str = "high_level_api::GenericDynInteger";
+}
+
+fn with_integer_key<R, F>(func: F) -> R
+where
+    F: FnOnce(&crate::integer::ServerKey) -> R,
+{
+    crate::high_level_api::global_state::with_internal_keys(|keys| func(keys.integer_key.pbs_key()))
+}
+
+impl<T> GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    pub(in crate::high_level_api) fn new(ciphertext: T) -> Self {
+        Self { ciphertext }
+    }
+
+    /// Returns the number of blocks of `self`.
+    pub fn num_blocks(&self) -> usize {
+        self.ciphertext.blocks().len()
+    }
+
+    /// Casts `self` to an integer of `num_blocks` blocks.
+    ///
+    /// The casting rules are the ones of the static types: a signed integer is sign extended,
+    /// an unsigned one is zero extended, and the most significant blocks are dropped when the
+    /// integer gets smaller.
+    pub fn cast_to(&self, num_blocks: usize) -> Self {
+        let ciphertext = with_integer_key(|integer_key| {
+            let blocks = cast_blocks(
+                integer_key,
+                self.ciphertext.blocks().to_vec(),
+                T::IS_SIGNED,
+                num_blocks,
+            );
+            T::from_blocks(blocks)
+        });
+        Self::new(ciphertext)
+    }
+
+    pub fn abs(&self) -> Self {
+        let ciphertext =
+            with_integer_key(|integer_key| integer_key.abs_parallelized(&self.ciphertext));
+        Self::new(ciphertext)
+    }
+
+    #[track_caller]
+    fn assert_same_num_blocks(&self, other: &Self) {
+        assert_eq!(
+            self.num_blocks(),
+            other.num_blocks(),
+            "Both operands must have the same number of blocks"
+        );
+    }
+}
+
+impl<T, Id> CastFrom<GenericInteger<Id>> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+    Id: IntegerId,
+{
+    /// The number of blocks is kept, only the signedness may change.
+    fn cast_from(input: GenericInteger<Id>) -> Self {
+        Self::new(T::from_blocks(input.ciphertext.into_blocks()))
+    }
+}
+
+impl<T, Id> CastFrom<GenericDynInteger<T>> for GenericInteger<Id>
+where
+    T: IntegerRadixCiphertext,
+    Id: IntegerId,
+{
+    fn cast_from(input: GenericDynInteger<T>) -> Self {
+        let ciphertext = with_integer_key(|integer_key| {
+            let blocks = cast_blocks(
+                integer_key,
+                input.ciphertext.into_blocks(),
+                T::IS_SIGNED,
+                Id::num_blocks(),
+            );
+            Id::InnerCiphertext::from_blocks(blocks)
+        });
+        Self::new(ciphertext, Id::default())
+    }
+}
+
+impl CastFrom<FheUintDyn> for FheIntDyn {
+    /// The number of blocks is kept, only the signedness changes.
+    fn cast_from(input: FheUintDyn) -> Self {
+        Self::new(SignedRadixCiphertext::from_blocks(
+            input.ciphertext.into_blocks(),
+        ))
+    }
+}
+
+impl CastFrom<FheIntDyn> for FheUintDyn {
+    /// The number of blocks is kept, only the signedness changes.
+    fn cast_from(input: FheIntDyn) -> Self {
+        Self::new(RadixCiphertext::from_blocks(input.ciphertext.into_blocks()))
+    }
+}
+
+impl<T, ClearType> FheDecrypt<ClearType> for GenericDynInteger<T>
+where
+    crate::integer::ClientKey: DecryptionKey<T, ClearType>,
+{
+    fn decrypt(&self, key: &ClientKey) -> ClearType {
+        let key = &key.key.key;
+        key.decrypt(&self.ciphertext)
+    }
+}
+
+impl<T, Clear> FheTryEncrypt<(Clear, usize), ClientKey> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+    crate::integer::ClientKey: EncryptionKey<(Clear, usize), T>,
+{
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(value: (Clear, usize), key: &ClientKey) -> Result<Self, Self::Error> {
+        let integer_client_key = &key.key.key;
+        let ciphertext =
+            <crate::integer::ClientKey as EncryptionKey<_, _>>::encrypt(integer_client_key, value);
+        Ok(Self::new(ciphertext))
+    }
+}
+
+impl<T, Clear> FheTryEncrypt<(Clear, usize), PublicKey> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+    crate::integer::PublicKey: EncryptionKey<(Clear, usize), T>,
+{
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(value: (Clear, usize), key: &PublicKey) -> Result<Self, Self::Error> {
+        let integer_public_key = &key.key;
+        let ciphertext =
+            <crate::integer::PublicKey as EncryptionKey<_, _>>::encrypt(integer_public_key, value);
+        Ok(Self::new(ciphertext))
+    }
+}
+
+impl<T, Clear> FheTryEncrypt<(Clear, usize), CompressedPublicKey> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+    crate::integer::CompressedPublicKey: EncryptionKey<(Clear, usize), T>,
+{
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(value: (Clear, usize), key: &CompressedPublicKey) -> Result<Self, Self::Error> {
+        let integer_public_key = &key.key;
+        let ciphertext = <crate::integer::CompressedPublicKey as EncryptionKey<_, _>>::encrypt(
+            integer_public_key,
+            value,
+        );
+        Ok(Self::new(ciphertext))
+    }
+}
+
+impl<T, Clear> FheTryEncrypt<(Clear, usize), CompactPublicKey> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+    crate::integer::public_key::CompactPublicKey: EncryptionKey<(Clear, usize), T>,
+{
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(value: (Clear, usize), key: &CompactPublicKey) -> Result<Self, Self::Error> {
+        let integer_public_key = &key.key.key;
+        let ciphertext =
+            <crate::integer::public_key::CompactPublicKey as EncryptionKey<_, _>>::encrypt(
+                integer_public_key,
+                value,
+            );
+        Ok(Self::new(ciphertext))
+    }
+}
+
+impl<T, Clear> FheTryTrivialEncrypt<(Clear, usize)> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+    Clear: DecomposableInto<u64>,
+{
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt_trivial((value, num_blocks): (Clear, usize)) -> Result<Self, Self::Error> {
+        let ciphertext =
+            with_integer_key(|integer_key| integer_key.create_trivial_radix(value, num_blocks));
+        Ok(Self::new(ciphertext))
+    }
+}
+
+impl<T, Clear> FheTrivialEncrypt<(Clear, usize)> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+    Clear: DecomposableInto<u64>,
+{
+    #[track_caller]
+    fn encrypt_trivial(value: (Clear, usize)) -> Self {
+        Self::try_encrypt_trivial(value).unwrap()
+    }
+}
+
+impl<T> FheMax<&Self> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    type Output = Self;
+
+    fn max(&self, rhs: &Self) -> Self::Output {
+        self.assert_same_num_blocks(rhs);
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.max_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        Self::new(inner_result)
+    }
+}
+
+impl<T, Clear> FheMax<Clear> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+    Clear: DecomposableInto<u64>,
+{
+    type Output = Self;
+
+    fn max(&self, rhs: Clear) -> Self::Output {
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.scalar_max_parallelized(&self.ciphertext, rhs)
+        });
+        Self::new(inner_result)
+    }
+}
+
+impl<T> FheMin<&Self> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    type Output = Self;
+
+    fn min(&self, rhs: &Self) -> Self::Output {
+        self.assert_same_num_blocks(rhs);
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.min_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        Self::new(inner_result)
+    }
+}
+
+impl<T, Clear> FheMin<Clear> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+    Clear: DecomposableInto<u64>,
+{
+    type Output = Self;
+
+    fn min(&self, rhs: Clear) -> Self::Output {
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.scalar_min_parallelized(&self.ciphertext, rhs)
+        });
+        Self::new(inner_result)
+    }
+}
+
+impl<T> FheEq<Self> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    fn eq(&self, rhs: Self) -> FheBool {
+        <Self as FheEq<&Self>>::eq(self, &rhs)
+    }
+
+    fn ne(&self, rhs: Self) -> FheBool {
+        <Self as FheEq<&Self>>::ne(self, &rhs)
+    }
+}
+
+impl<T> FheEq<&Self> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    fn eq(&self, rhs: &Self) -> FheBool {
+        self.assert_same_num_blocks(rhs);
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.eq_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheBool::new(inner_result)
+    }
+
+    fn ne(&self, rhs: &Self) -> FheBool {
+        self.assert_same_num_blocks(rhs);
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.ne_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheBool::new(inner_result)
+    }
+}
+
+impl<T, Clear> FheEq<Clear> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+    Clear: DecomposableInto<u64>,
+{
+    fn eq(&self, rhs: Clear) -> FheBool {
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.scalar_eq_parallelized(&self.ciphertext, rhs)
+        });
+        FheBool::new(inner_result)
+    }
+
+    fn ne(&self, rhs: Clear) -> FheBool {
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.scalar_ne_parallelized(&self.ciphertext, rhs)
+        });
+        FheBool::new(inner_result)
+    }
+}
+
+impl<T> FheOrd<Self> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    fn lt(&self, rhs: Self) -> FheBool {
+        <Self as FheOrd<&Self>>::lt(self, &rhs)
+    }
+
+    fn le(&self, rhs: Self) -> FheBool {
+        <Self as FheOrd<&Self>>::le(self, &rhs)
+    }
+
+    fn gt(&self, rhs: Self) -> FheBool {
+        <Self as FheOrd<&Self>>::gt(self, &rhs)
+    }
+
+    fn ge(&self, rhs: Self) -> FheBool {
+        <Self as FheOrd<&Self>>::ge(self, &rhs)
+    }
+}
+
+impl<T> FheOrd<&Self> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    fn lt(&self, rhs: &Self) -> FheBool {
+        self.assert_same_num_blocks(rhs);
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.lt_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheBool::new(inner_result)
+    }
+
+    fn le(&self, rhs: &Self) -> FheBool {
+        self.assert_same_num_blocks(rhs);
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.le_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheBool::new(inner_result)
+    }
+
+    fn gt(&self, rhs: &Self) -> FheBool {
+        self.assert_same_num_blocks(rhs);
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.gt_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheBool::new(inner_result)
+    }
+
+    fn ge(&self, rhs: &Self) -> FheBool {
+        self.assert_same_num_blocks(rhs);
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.ge_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheBool::new(inner_result)
+    }
+}
+
+impl<T, Clear> FheOrd<Clear> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+    Clear: DecomposableInto<u64>,
+{
+    fn lt(&self, rhs: Clear) -> FheBool {
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.scalar_lt_parallelized(&self.ciphertext, rhs)
+        });
+        FheBool::new(inner_result)
+    }
+
+    fn le(&self, rhs: Clear) -> FheBool {
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.scalar_le_parallelized(&self.ciphertext, rhs)
+        });
+        FheBool::new(inner_result)
+    }
+
+    fn gt(&self, rhs: Clear) -> FheBool {
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.scalar_gt_parallelized(&self.ciphertext, rhs)
+        });
+        FheBool::new(inner_result)
+    }
+
+    fn ge(&self, rhs: Clear) -> FheBool {
+        let inner_result = with_integer_key(|integer_key| {
+            integer_key.scalar_ge_parallelized(&self.ciphertext, rhs)
+        });
+        FheBool::new(inner_result)
+    }
+}
+
+impl<T> DivRem<Self> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    type Output = (Self, Self);
+
+    fn div_rem(self, rhs: Self) -> Self::Output {
+        <&Self as DivRem<&Self>>::div_rem(&self, &rhs)
+    }
+}
+
+impl<T> DivRem<&Self> for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    type Output = (Self, Self);
+
+    fn div_rem(self, rhs: &Self) -> Self::Output {
+        <&Self as DivRem<&Self>>::div_rem(&self, rhs)
+    }
+}
+
+impl<T> DivRem<Self> for &GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    type Output = (GenericDynInteger<T>, GenericDynInteger<T>);
+
+    fn div_rem(self, rhs: Self) -> Self::Output {
+        self.assert_same_num_blocks(rhs);
+        let (q, r) = with_integer_key(|integer_key| {
+            integer_key.div_rem_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        (GenericDynInteger::new(q), GenericDynInteger::new(r))
+    }
+}
+
+// As for the static types, the right hand side of shifts and rotations
+// is required to be an unsigned integer, but it may have any number of blocks.
+macro_rules! dyn_integer_impl_shift_rotate (
+    ($rust_trait_name:ident($rust_trait_method:ident) => $key_method:ident) => {
+
+        // a op b
+        impl<T> $rust_trait_name<FheUintDyn> for GenericDynInteger<T>
+        where
+            T: IntegerRadixCiphertext,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: FheUintDyn) -> Self::Output {
+                <&Self as $rust_trait_name<&FheUintDyn>>::$rust_trait_method(&self, &rhs)
+            }
+        }
+
+        // a op &b
+        impl<T> $rust_trait_name<&FheUintDyn> for GenericDynInteger<T>
+        where
+            T: IntegerRadixCiphertext,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: &FheUintDyn) -> Self::Output {
+                <&Self as $rust_trait_name<&FheUintDyn>>::$rust_trait_method(&self, rhs)
+            }
+        }
+
+        // &a op b
+        impl<T> $rust_trait_name<FheUintDyn> for &GenericDynInteger<T>
+        where
+            T: IntegerRadixCiphertext,
+        {
+            type Output = GenericDynInteger<T>;
+
+            fn $rust_trait_method(self, rhs: FheUintDyn) -> Self::Output {
+                <Self as $rust_trait_name<&FheUintDyn>>::$rust_trait_method(self, &rhs)
+            }
+        }
+
+        // &a op &b
+        impl<T> $rust_trait_name<&FheUintDyn> for &GenericDynInteger<T>
+        where
+            T: IntegerRadixCiphertext,
+        {
+            type Output = GenericDynInteger<T>;
+
+            fn $rust_trait_method(self, rhs: &FheUintDyn) -> Self::Output {
+                let ciphertext = with_integer_key(|integer_key| {
+                    integer_key.$key_method(&self.ciphertext, &rhs.ciphertext)
+                });
+                GenericDynInteger::new(ciphertext)
+            }
+        }
+    }
+);
+
+macro_rules! dyn_integer_impl_shift_rotate_assign(
+    ($rust_trait_name:ident($rust_trait_method:ident) => $key_method:ident) => {
+        // a op= b
+        impl<T> $rust_trait_name<FheUintDyn> for GenericDynInteger<T>
+        where
+            T: IntegerRadixCiphertext,
+        {
+            fn $rust_trait_method(&mut self, rhs: FheUintDyn) {
+                <Self as $rust_trait_name<&FheUintDyn>>::$rust_trait_method(self, &rhs)
+            }
+        }
+
+        // a op= &b
+        impl<T> $rust_trait_name<&FheUintDyn> for GenericDynInteger<T>
+        where
+            T: IntegerRadixCiphertext,
+        {
+            fn $rust_trait_method(&mut self, rhs: &FheUintDyn) {
+                with_integer_key(|integer_key| {
+                    integer_key.$key_method(&mut self.ciphertext, &rhs.ciphertext)
+                })
+            }
+        }
+    }
+);
+
+macro_rules! dyn_integer_impl_operation (
+    ($rust_trait_name:ident($rust_trait_method:ident) => $key_method:ident) => {
+
+        impl<T, B> $rust_trait_name<B> for GenericDynInteger<T>
+        where
+            T: IntegerRadixCiphertext,
+            B: Borrow<Self>,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                <&Self as $rust_trait_name<B>>::$rust_trait_method(&self, rhs)
+            }
+        }
+
+        impl<T, B> $rust_trait_name<B> for &GenericDynInteger<T>
+        where
+            T: IntegerRadixCiphertext,
+            B: Borrow<GenericDynInteger<T>>,
+        {
+            type Output = GenericDynInteger<T>;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                let borrowed = rhs.borrow();
+                self.assert_same_num_blocks(borrowed);
+                let ciphertext = with_integer_key(|integer_key| {
+                    integer_key.$key_method(&self.ciphertext, &borrowed.ciphertext)
+                });
+                GenericDynInteger::new(ciphertext)
+            }
+        }
+    }
+);
+
+macro_rules! dyn_integer_impl_operation_assign (
+    ($rust_trait_name:ident($rust_trait_method:ident) => $key_method:ident) => {
+        impl<T, I> $rust_trait_name<I> for GenericDynInteger<T>
+        where
+            T: IntegerRadixCiphertext,
+            I: Borrow<Self>,
+        {
+            fn $rust_trait_method(&mut self, rhs: I) {
+                let borrowed = rhs.borrow();
+                self.assert_same_num_blocks(borrowed);
+                with_integer_key(|integer_key| {
+                    integer_key.$key_method(&mut self.ciphertext, &borrowed.ciphertext)
+                })
+            }
+        }
+    }
+);
+
+macro_rules! dyn_integer_impl_scalar_operation {
+    (
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident),
+        key_method: $key_method:ident,
+        // A 'list' of tuple, where the first element is the concrete Fhe type
+        // e.g (FheUintDyn and the rest is scalar types (u8, u16, etc)
+        fhe_and_scalar_type: $(
+            ($concrete_type:ty, $($scalar_type:ty),*)
+        ),*
+        $(,)?
+    ) => {
+        $( // First repeating pattern
+            $( // Second repeating pattern
+                impl $rust_trait_name<$scalar_type> for $concrete_type
+                {
+                    type Output = $concrete_type;
+
+                    fn $rust_trait_method(self, rhs: $scalar_type) -> Self::Output {
+                        <&Self as $rust_trait_name<$scalar_type>>::$rust_trait_method(&self, rhs)
+                    }
+                }
+
+                impl $rust_trait_name<$scalar_type> for &$concrete_type
+                {
+                    type Output = $concrete_type;
+
+                    fn $rust_trait_method(self, rhs: $scalar_type) -> Self::Output {
+                        let ciphertext = with_integer_key(|integer_key| {
+                            integer_key.$key_method(&self.ciphertext, rhs)
+                        });
+                        <$concrete_type>::new(ciphertext)
+                    }
+                }
+            )* // Closing second repeating pattern
+        )* // Closing first repeating pattern
+    };
+}
+
+macro_rules! dyn_integer_impl_scalar_operation_assign {
+    (
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident),
+        key_method: $key_method:ident,
+        // A 'list' of tuple, where the first element is the concrete Fhe type
+        // e.g (FheUintDyn and the rest is scalar types (u8, u16, etc)
+        fhe_and_scalar_type: $(
+            ($concrete_type:ty, $($scalar_type:ty),*)
+        ),*
+        $(,)?
+    ) => {
+        $(
+            $(
+                impl $rust_trait_name<$scalar_type> for $concrete_type
+                {
+                    fn $rust_trait_method(&mut self, rhs: $scalar_type) {
+                        with_integer_key(|integer_key| {
+                            integer_key.$key_method(&mut self.ciphertext, rhs);
+                        })
+                    }
+                }
+            )*
+        )*
+    }
+}
+
+dyn_integer_impl_operation!(Add(add) => add_parallelized);
+dyn_integer_impl_operation!(Sub(sub) => sub_parallelized);
+dyn_integer_impl_operation!(Mul(mul) => mul_parallelized);
+dyn_integer_impl_operation!(BitAnd(bitand) => bitand_parallelized);
+dyn_integer_impl_operation!(BitOr(bitor) => bitor_parallelized);
+dyn_integer_impl_operation!(BitXor(bitxor) => bitxor_parallelized);
+dyn_integer_impl_operation!(Div(div) => div_parallelized);
+dyn_integer_impl_operation!(Rem(rem) => rem_parallelized);
+dyn_integer_impl_shift_rotate!(Shl(shl) => left_shift_parallelized);
+dyn_integer_impl_shift_rotate!(Shr(shr) => right_shift_parallelized);
+dyn_integer_impl_shift_rotate!(RotateLeft(rotate_left) => rotate_left_parallelized);
+dyn_integer_impl_shift_rotate!(RotateRight(rotate_right) => rotate_right_parallelized);
+// assign operations
+dyn_integer_impl_operation_assign!(AddAssign(add_assign) => add_assign_parallelized);
+dyn_integer_impl_operation_assign!(SubAssign(sub_assign) => sub_assign_parallelized);
+dyn_integer_impl_operation_assign!(MulAssign(mul_assign) => mul_assign_parallelized);
+dyn_integer_impl_operation_assign!(BitAndAssign(bitand_assign) => bitand_assign_parallelized);
+dyn_integer_impl_operation_assign!(BitOrAssign(bitor_assign) => bitor_assign_parallelized);
+dyn_integer_impl_operation_assign!(BitXorAssign(bitxor_assign) => bitxor_assign_parallelized);
+dyn_integer_impl_operation_assign!(DivAssign(div_assign) => div_assign_parallelized);
+dyn_integer_impl_operation_assign!(RemAssign(rem_assign) => rem_assign_parallelized);
+dyn_integer_impl_shift_rotate_assign!(ShlAssign(shl_assign) => left_shift_assign_parallelized);
+dyn_integer_impl_shift_rotate_assign!(ShrAssign(shr_assign) => right_shift_assign_parallelized);
+dyn_integer_impl_shift_rotate_assign!(RotateLeftAssign(rotate_left_assign) => rotate_left_assign_parallelized);
+dyn_integer_impl_shift_rotate_assign!(RotateRightAssign(rotate_right_assign) => rotate_right_assign_parallelized);
+
+dyn_integer_impl_scalar_operation!(
+    rust_trait: Add(add),
+    key_method: scalar_add_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+dyn_integer_impl_scalar_operation!(
+    rust_trait: Sub(sub),
+    key_method: scalar_sub_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+dyn_integer_impl_scalar_operation!(
+    rust_trait: Mul(mul),
+    key_method: scalar_mul_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+dyn_integer_impl_scalar_operation!(
+    rust_trait: BitAnd(bitand),
+    key_method: scalar_bitand_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+dyn_integer_impl_scalar_operation!(
+    rust_trait: BitOr(bitor),
+    key_method: scalar_bitor_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+dyn_integer_impl_scalar_operation!(
+    rust_trait: BitXor(bitxor),
+    key_method: scalar_bitxor_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+dyn_integer_impl_scalar_operation!(
+    rust_trait: Shl(shl),
+    key_method: scalar_left_shift_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, u8, u16, u32, u64, u128, U256),
+);
+dyn_integer_impl_scalar_operation!(
+    rust_trait: Shr(shr),
+    key_method: scalar_right_shift_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, u8, u16, u32, u64, u128, U256),
+);
+dyn_integer_impl_scalar_operation!(
+    rust_trait: RotateLeft(rotate_left),
+    key_method: scalar_rotate_left_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, u8, u16, u32, u64, u128, U256),
+);
+dyn_integer_impl_scalar_operation!(
+    rust_trait: RotateRight(rotate_right),
+    key_method: scalar_rotate_right_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, u8, u16, u32, u64, u128, U256),
+);
+dyn_integer_impl_scalar_operation!(
+    rust_trait: Div(div),
+    key_method: scalar_div_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+);
+dyn_integer_impl_scalar_operation!(
+    rust_trait: Rem(rem),
+    key_method: scalar_rem_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+);
+dyn_integer_impl_scalar_operation!(
+    rust_trait: Div(div),
+    key_method: signed_scalar_div_parallelized,
+    fhe_and_scalar_type:
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+dyn_integer_impl_scalar_operation!(
+    rust_trait: Rem(rem),
+    key_method: signed_scalar_rem_parallelized,
+    fhe_and_scalar_type:
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+// Scalar assign ops
+dyn_integer_impl_scalar_operation_assign!(
+    rust_trait: AddAssign(add_assign),
+    key_method: scalar_add_assign_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+dyn_integer_impl_scalar_operation_assign!(
+    rust_trait: SubAssign(sub_assign),
+    key_method: scalar_sub_assign_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+dyn_integer_impl_scalar_operation_assign!(
+    rust_trait: MulAssign(mul_assign),
+    key_method: scalar_mul_assign_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+dyn_integer_impl_scalar_operation_assign!(
+    rust_trait: BitAndAssign(bitand_assign),
+    key_method: scalar_bitand_assign_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+dyn_integer_impl_scalar_operation_assign!(
+    rust_trait: BitOrAssign(bitor_assign),
+    key_method: scalar_bitor_assign_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+dyn_integer_impl_scalar_operation_assign!(
+    rust_trait: BitXorAssign(bitxor_assign),
+    key_method: scalar_bitxor_assign_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+dyn_integer_impl_scalar_operation_assign!(
+    rust_trait: ShlAssign(shl_assign),
+    key_method: scalar_left_shift_assign_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, u8, u16, u32, u64, u128, U256),
+);
+dyn_integer_impl_scalar_operation_assign!(
+    rust_trait: ShrAssign(shr_assign),
+    key_method: scalar_right_shift_assign_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, u8, u16, u32, u64, u128, U256),
+);
+dyn_integer_impl_scalar_operation_assign!(
+    rust_trait: RotateLeftAssign(rotate_left_assign),
+    key_method: scalar_rotate_left_assign_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, u8, u16, u32, u64, u128, U256),
+);
+dyn_integer_impl_scalar_operation_assign!(
+    rust_trait: RotateRightAssign(rotate_right_assign),
+    key_method: scalar_rotate_right_assign_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+        (FheIntDyn, u8, u16, u32, u64, u128, U256),
+);
+dyn_integer_impl_scalar_operation_assign!(
+    rust_trait: DivAssign(div_assign),
+    key_method: scalar_div_assign_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+);
+dyn_integer_impl_scalar_operation_assign!(
+    rust_trait: RemAssign(rem_assign),
+    key_method: scalar_rem_assign_parallelized,
+    fhe_and_scalar_type:
+        (FheUintDyn, u8, u16, u32, u64, u128, U256),
+);
+dyn_integer_impl_scalar_operation_assign!(
+    rust_trait: DivAssign(div_assign),
+    key_method: signed_scalar_div_assign_parallelized,
+    fhe_and_scalar_type:
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+dyn_integer_impl_scalar_operation_assign!(
+    rust_trait: RemAssign(rem_assign),
+    key_method: signed_scalar_rem_assign_parallelized,
+    fhe_and_scalar_type:
+        (FheIntDyn, i8, i16, i32, i64, i128, I256),
+);
+
+impl<T> Neg for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        <&Self as Neg>::neg(&self)
+    }
+}
+
+impl<T> Neg for &GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    type Output = GenericDynInteger<T>;
+
+    fn neg(self) -> Self::Output {
+        let ciphertext =
+            with_integer_key(|integer_key| integer_key.neg_parallelized(&self.ciphertext));
+        GenericDynInteger::new(ciphertext)
+    }
+}
+
+impl<T> Not for GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        <&Self as Not>::not(&self)
+    }
+}
+
+impl<T> Not for &GenericDynInteger<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    type Output = GenericDynInteger<T>;
+
+    fn not(self) -> Self::Output {
+        let ciphertext =
+            with_integer_key(|integer_key| integer_key.bitnot_parallelized(&self.ciphertext));
+        GenericDynInteger::new(ciphertext)
+    }
+}
@@ -1,4 +1,5 @@
 pub use base::GenericInteger;
+pub use dynamic::{FheIntDyn, FheUintDyn};
 
 expand_pub_use_fhe_type!(
     pub use static_{
@@ -10,4 +11,5 @@ expand_pub_use_fhe_type!(
 pub(super) mod base;
 pub(super) mod compact;
 pub(super) mod compressed;
+pub(super) mod dynamic;
 pub(super) mod static_;
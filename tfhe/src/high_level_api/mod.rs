@@ -35,18 +35,25 @@ pub use keys::{
 mod tests;
 
 pub use crate::high_level_api::booleans::{CompressedFheBool, FheBool};
+pub use crate::high_level_api::strings::{
+    set_string_execution_mode, string_execution_mode, CompactFheAsciiString,
+    CompressedFheAsciiString, FheAsciiString, FheAsciiStringMap,
+};
 expand_pub_use_fhe_type!(
     pub use crate::high_level_api::integers{
         FheUint8, FheUint10, FheUint12, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128,
         FheUint256, FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256
     };
 );
+pub use crate::high_level_api::integers::{FheIntDyn, FheUintDyn};
 #[cfg(feature = "safe-deserialization")]
 pub use integers::safe_serialize::{
     safe_deserialize_conformant, safe_deserialize_conformant_compact_integer,
-    safe_deserialize_conformant_compressed_integer, safe_deserialize_conformant_integer,
-    safe_serialize,
+    safe_deserialize_conformant_compressed_integer, safe_deserialize_conformant_dyn_integer,
+    safe_deserialize_conformant_integer, safe_serialize,
 };
+#[cfg(feature = "safe-deserialization")]
+pub use strings::safe_serialize::safe_deserialize_conformant_string;
 
 #[macro_use]
 mod global_state;
@@ -59,6 +66,7 @@ mod traits;
 mod booleans;
 pub mod errors;
 mod integers;
+mod strings;
 
 /// The tfhe prelude.
 pub mod prelude;
@@ -0,0 +1,95 @@
+use super::{string_server_key, FheAsciiString};
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::integers::{GenericInteger, IntegerId};
+use crate::integer::RadixCiphertext;
+use crate::strings::FheStringMap;
+use crate::FheBool;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// A table mapping strings to encrypted unsigned integers, which can be queried with an
+/// encrypted string without revealing which entry matches.
+///
+/// Keys can be encrypted or, for a public table, trivially encrypted strings. They are expected
+/// to be distinct.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{
+///     generate_keys, set_server_key, ConfigBuilder, FheAsciiString, FheAsciiStringMap, FheUint8,
+/// };
+///
+/// let config = ConfigBuilder::default().build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let mut map = FheAsciiStringMap::new();
+/// map.insert(
+///     FheAsciiString::encrypt_trivial("alice"),
+///     FheUint8::encrypt(12u8, &client_key),
+/// );
+/// map.insert(
+///     FheAsciiString::encrypt_trivial("bob"),
+///     FheUint8::encrypt(34u8, &client_key),
+/// );
+///
+/// let query = FheAsciiString::try_encrypt_with_padding("bob", 2, &client_key).unwrap();
+/// let (value, found) = map.get(&query);
+///
+/// assert!(found.decrypt(&client_key));
+/// let value: u8 = value.decrypt(&client_key);
+/// assert_eq!(value, 34);
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FheAsciiStringMap<T> {
+    inner: FheStringMap,
+    _marker: PhantomData<T>,
+}
+
+impl<Id> FheAsciiStringMap<GenericInteger<Id>>
+where
+    Id: IntegerId<InnerCiphertext = RadixCiphertext>,
+{
+    pub fn new() -> Self {
+        Self {
+            inner: FheStringMap::new(Id::num_blocks()),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn insert(&mut self, key: FheAsciiString, value: GenericInteger<Id>) {
+        self.inner.insert(key.inner, value.ciphertext);
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the value associated to the query, and whether it was found.
+    ///
+    /// The returned value encrypts 0 if the query is not found.
+    pub fn get(&self, query: &FheAsciiString) -> (GenericInteger<Id>, FheBool) {
+        let (value, is_found) = with_internal_keys(|keys| {
+            string_server_key(keys.integer_key.pbs_key()).lookup(&self.inner, &query.inner)
+        });
+        (
+            GenericInteger::new(value, Id::default()),
+            FheBool::new(is_found),
+        )
+    }
+}
+
+impl<Id> Default for FheAsciiStringMap<GenericInteger<Id>>
+where
+    Id: IntegerId<InnerCiphertext = RadixCiphertext>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
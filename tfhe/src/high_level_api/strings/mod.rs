@@ -0,0 +1,726 @@
+use crate::conformance::ParameterSetConformant;
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::integers::{
+    FheUint16Id, FheUint32Id, FheUint8Id, GenericInteger, IntegerId,
+};
+use crate::high_level_api::keys::ClientKey;
+use crate::high_level_api::traits::{
+    FheDecrypt, FheEq, FheOrd, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt,
+};
+use crate::integer::{BooleanBlock, IntegerCiphertext, RadixCiphertext};
+use crate::named::Named;
+use crate::strings::client_key::is_valid_ascii;
+use crate::strings::sha256::Adder;
+use crate::strings::{
+    CompactFheString, CompressedFheString, ExecutionMode, FheAsciiChar, FheString,
+    FheStringConformanceParams,
+};
+use crate::{
+    CompactPublicKey, CompressedPublicKey, FheBool, FheUint16, FheUint32, FheUint8,
+    OutOfRangeError, PublicKey,
+};
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+
+mod map;
+#[cfg(test)]
+mod tests;
+
+pub use map::FheAsciiStringMap;
+
+type StringServerKey<'a> = crate::strings::ServerKey<&'a crate::integer::ServerKey>;
+type StringClientKey<'a> = crate::strings::ClientKey<&'a crate::integer::ClientKey>;
+
+thread_local! {
+    static EXECUTION_MODE: Cell<ExecutionMode> = Cell::new(ExecutionMode::default());
+}
+
+/// Sets the [ExecutionMode] of the string operations.
+///
+/// Like the server key, the mode is stored per thread: it must be set on each thread doing
+/// string operations, and is [ExecutionMode::ConstantTime] until then.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::strings::ExecutionMode;
+/// use tfhe::{
+///     generate_keys, set_server_key, set_string_execution_mode, ConfigBuilder, FheAsciiString,
+///     FheUint8,
+/// };
+///
+/// let config = ConfigBuilder::default().build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheAsciiString::encrypt("tfhe", &client_key);
+/// let (start, end) = (
+///     FheUint8::encrypt_trivial(1u8),
+///     FheUint8::encrypt_trivial(3u8),
+/// );
+///
+/// // The indices being trivial, the optimized mode knows the length of the substring
+/// set_string_execution_mode(ExecutionMode::Optimized);
+/// let b = a.substring(&start, &end);
+/// assert_eq!(b.len(), 2);
+///
+/// let decrypted: String = b.decrypt(&client_key);
+/// assert_eq!(decrypted, "fh");
+/// ```
+pub fn set_string_execution_mode(mode: ExecutionMode) {
+    EXECUTION_MODE.with(|execution_mode| execution_mode.set(mode));
+}
+
+/// Returns the [ExecutionMode] of the string operations run by this thread.
+pub fn string_execution_mode() -> ExecutionMode {
+    EXECUTION_MODE.with(Cell::get)
+}
+
+/// Returns the string server key running in the mode set for this thread.
+fn string_server_key(integer_key: &crate::integer::ServerKey) -> StringServerKey<'_> {
+    StringServerKey::new_with_mode(integer_key, string_execution_mode())
+}
+
+/// The FHE ASCII string data type.
+///
+/// Each character is encrypted on 8 bits. The string can be encrypted with some padding
+/// (null characters appended after its content) to hide its real length.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+///
+/// let config = ConfigBuilder::default().build();
+///
+/// let (client_key, server_key) = generate_keys(config);
+///
+/// let a = FheAsciiString::encrypt("tfhe", &client_key);
+/// let b = FheAsciiString::try_encrypt_with_padding("tfhe", 4, &client_key).unwrap();
+///
+/// // Do not forget to set the server key before doing any computation
+/// set_server_key(server_key);
+///
+/// let fhe_result = a.eq(&b);
+///
+/// let clear_result = fhe_result.decrypt(&client_key);
+/// assert!(clear_result);
+///
+/// let decrypted: String = b.decrypt(&client_key);
+/// assert_eq!(decrypted, "tfhe");
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FheAsciiString {
+    pub(in crate::high_level_api) inner: FheString,
+}
+
+impl FheAsciiString {
+    pub(in crate::high_level_api) fn new(inner: FheString) -> Self {
+        Self { inner }
+    }
+
+    /// Encrypts the string, appending `padding` encrypted null characters to hide its length.
+    pub fn try_encrypt_with_padding(
+        value: &str,
+        padding: u32,
+        key: &ClientKey,
+    ) -> Result<Self, crate::high_level_api::errors::Error> {
+        if !is_valid_ascii(value) {
+            return Err(OutOfRangeError.into());
+        }
+        let string_client_key = StringClientKey::from(&key.key.key);
+        let inner = string_client_key.encrypt_ascii(value, Some(padding));
+        Ok(Self::new(inner))
+    }
+
+    /// Returns the number of encrypted characters, padding included.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns whether the string may end with encrypted null characters.
+    pub fn is_padded(&self) -> bool {
+        self.inner.is_padded()
+    }
+
+    /// Returns the character at the encrypted `index`, which is an encrypted null character if
+    /// the index is out of bounds.
+    pub fn char_at<Id>(&self, index: &GenericInteger<Id>) -> FheUint8
+    where
+        Id: IntegerId<InnerCiphertext = RadixCiphertext>,
+    {
+        let char = with_internal_keys(|keys| {
+            string_server_key(keys.integer_key.pbs_key()).char_at(&self.inner, &index.ciphertext)
+        });
+        FheUint8::new(char.into_ciphertext(), FheUint8Id)
+    }
+
+    /// Returns the substring from the encrypted `start` index (included) to the encrypted `end`
+    /// index (excluded).
+    ///
+    /// The result has the same number of characters as `self`, the ones after the end of the
+    /// substring being padding.
+    pub fn substring<Id>(&self, start: &GenericInteger<Id>, end: &GenericInteger<Id>) -> Self
+    where
+        Id: IntegerId<InnerCiphertext = RadixCiphertext>,
+    {
+        let inner = with_internal_keys(|keys| {
+            string_server_key(keys.integer_key.pbs_key()).substring(
+                &self.inner,
+                &start.ciphertext,
+                &end.ciphertext,
+            )
+        });
+        Self::new(inner)
+    }
+
+    /// Pads the string on the left with the `fill` character up to the encrypted `width`.
+    ///
+    /// The result has `max(self.len(), max_width)` characters, see
+    /// [crate::strings::ServerKey::pad_start].
+    pub fn pad_start<Id>(
+        &self,
+        width: &GenericInteger<Id>,
+        fill: &FheUint8,
+        max_width: usize,
+    ) -> Self
+    where
+        Id: IntegerId<InnerCiphertext = RadixCiphertext>,
+    {
+        self.pad(width, fill, |string_key, str, width, fill| {
+            string_key.pad_start(str, width, fill, max_width)
+        })
+    }
+
+    /// Pads the string on the right with the `fill` character up to the encrypted `width`.
+    ///
+    /// The result has `max(self.len(), max_width)` characters, see
+    /// [crate::strings::ServerKey::pad_end].
+    pub fn pad_end<Id>(&self, width: &GenericInteger<Id>, fill: &FheUint8, max_width: usize) -> Self
+    where
+        Id: IntegerId<InnerCiphertext = RadixCiphertext>,
+    {
+        self.pad(width, fill, |string_key, str, width, fill| {
+            string_key.pad_end(str, width, fill, max_width)
+        })
+    }
+
+    /// Sorts the strings in lexicographic order, see [crate::strings::ServerKey::sort].
+    pub fn sort(strs: &[Self]) -> Vec<Self> {
+        let strs: Vec<_> = strs.iter().map(|str| str.inner.clone()).collect();
+        let sorted =
+            with_internal_keys(|keys| string_server_key(keys.integer_key.pbs_key()).sort(&strs));
+        sorted.into_iter().map(Self::new).collect()
+    }
+
+    /// Sorts the strings in the lexicographic order of the keys computed by `f`, see
+    /// [crate::strings::ServerKey::sort_by_key].
+    pub fn sort_by_key<F>(strs: &[Self], mut f: F) -> Vec<Self>
+    where
+        F: FnMut(&Self) -> Self,
+    {
+        // The keys are computed on this thread, where the server key is set
+        let sort_keys = strs.iter().map(|str| f(str).inner).collect();
+        let strs: Vec<_> = strs.iter().map(|str| str.inner.clone()).collect();
+        let sorted = with_internal_keys(|keys| {
+            string_server_key(keys.integer_key.pbs_key()).sort_with_keys(sort_keys, &strs)
+        });
+        sorted.into_iter().map(Self::new).collect()
+    }
+
+    /// Computes the SHA-256 digest of the string, as eight 32-bit words, the additions using
+    /// the given parallel prefix `adder`.
+    ///
+    /// The string may be padded, its real length is not revealed by the computation.
+    pub fn sha256(&self, adder: Adder) -> [FheUint32; 8] {
+        let digest = with_internal_keys(|keys| {
+            let string_key = string_server_key(keys.integer_key.pbs_key());
+            crate::strings::sha256::sha256(&string_key, &self.inner, adder)
+        });
+        digest.map(|word| FheUint32::new(word, FheUint32Id))
+    }
+
+    /// Encodes the string in lowercase hexadecimal, see
+    /// [crate::strings::ServerKey::hex_encode].
+    // The closures cannot be replaced by the method paths, as the lifetime of the borrowed
+    // server key is only known inside the helpers
+    #[allow(clippy::redundant_closure_for_method_calls)]
+    pub fn hex_encode(&self) -> Self {
+        self.map(|string_key, str| string_key.hex_encode(str))
+    }
+
+    /// Decodes a hexadecimal string, see [crate::strings::ServerKey::hex_decode].
+    ///
+    /// Returns the decoded string and whether the input was valid, the decoded string being
+    /// empty if it was not.
+    #[allow(clippy::redundant_closure_for_method_calls)]
+    pub fn hex_decode(&self) -> (Self, FheBool) {
+        self.decode(|string_key, str| string_key.hex_decode(str))
+    }
+
+    /// Encodes the string in base64, see [crate::strings::ServerKey::base64_encode].
+    #[allow(clippy::redundant_closure_for_method_calls)]
+    pub fn base64_encode(&self) -> Self {
+        self.map(|string_key, str| string_key.base64_encode(str))
+    }
+
+    /// Decodes a base64 string, see [crate::strings::ServerKey::base64_decode].
+    ///
+    /// Returns the decoded string and whether the input was valid, the decoded string being
+    /// empty if it was not.
+    #[allow(clippy::redundant_closure_for_method_calls)]
+    pub fn base64_decode(&self) -> (Self, FheBool) {
+        self.decode(|string_key, str| string_key.base64_decode(str))
+    }
+
+    /// Splits the string into tokens and maps them to their index in the clear `vocabulary`,
+    /// see [crate::strings::ServerKey::tokenize].
+    ///
+    /// The vocabulary must have less than `u16::MAX` words.
+    pub fn tokenize(&self, vocabulary: &[&str], max_tokens: usize) -> Vec<(FheUint16, FheBool)> {
+        assert!(
+            vocabulary.len() < usize::from(u16::MAX),
+            "The vocabulary must have less than {} words",
+            u16::MAX
+        );
+
+        let tokens = with_internal_keys(|keys| {
+            let integer_key = keys.integer_key.pbs_key();
+            let tokens =
+                string_server_key(integer_key).tokenize(&self.inner, vocabulary, max_tokens);
+            tokens
+                .into_iter()
+                .map(|(id, is_present)| {
+                    let num_blocks = FheUint16Id::num_blocks() - id.blocks().len();
+                    let id = integer_key.extend_radix_with_trivial_zero_blocks_msb(&id, num_blocks);
+                    (id, is_present)
+                })
+                .collect::<Vec<_>>()
+        });
+        tokens
+            .into_iter()
+            .map(|(id, is_present)| (FheUint16::new(id, FheUint16Id), FheBool::new(is_present)))
+            .collect()
+    }
+
+    /// Returns whether the string contains the `pattern`, see
+    /// [crate::strings::ServerKey::contains].
+    pub fn contains(&self, pattern: &Self) -> FheBool {
+        self.compare(&pattern.inner, |string_key, str, pattern| {
+            string_key.contains(str, pattern)
+        })
+    }
+
+    /// Returns whether the string starts with the `pattern`, see
+    /// [crate::strings::ServerKey::starts_with].
+    pub fn starts_with(&self, pattern: &Self) -> FheBool {
+        self.compare(&pattern.inner, |string_key, str, pattern| {
+            string_key.starts_with(str, pattern)
+        })
+    }
+
+    /// Returns the index of the first occurrence of the `pattern`, and whether it was found,
+    /// see [crate::strings::ServerKey::find].
+    ///
+    /// The string must have less than `u16::MAX` characters.
+    pub fn find(&self, pattern: &Self) -> (FheUint16, FheBool) {
+        assert!(
+            self.len() < usize::from(u16::MAX),
+            "The string must have less than {} characters",
+            u16::MAX
+        );
+
+        let (index, is_found) = with_internal_keys(|keys| {
+            let integer_key = keys.integer_key.pbs_key();
+            let (index, is_found) =
+                string_server_key(integer_key).find(&self.inner, &pattern.inner);
+            let num_blocks = FheUint16Id::num_blocks() - index.blocks().len();
+            let index = integer_key.extend_radix_with_trivial_zero_blocks_msb(&index, num_blocks);
+            (index, is_found)
+        });
+        (FheUint16::new(index, FheUint16Id), FheBool::new(is_found))
+    }
+
+    fn map<F>(&self, func: F) -> Self
+    where
+        F: FnOnce(&StringServerKey<'_>, &FheString) -> FheString,
+    {
+        let inner = with_internal_keys(|keys| {
+            let string_key = string_server_key(keys.integer_key.pbs_key());
+            func(&string_key, &self.inner)
+        });
+        Self::new(inner)
+    }
+
+    fn decode<F>(&self, func: F) -> (Self, FheBool)
+    where
+        F: FnOnce(&StringServerKey<'_>, &FheString) -> (FheString, BooleanBlock),
+    {
+        let (inner, is_valid) = with_internal_keys(|keys| {
+            let string_key = string_server_key(keys.integer_key.pbs_key());
+            func(&string_key, &self.inner)
+        });
+        (Self::new(inner), FheBool::new(is_valid))
+    }
+
+    fn pad<Id, F>(&self, width: &GenericInteger<Id>, fill: &FheUint8, func: F) -> Self
+    where
+        Id: IntegerId<InnerCiphertext = RadixCiphertext>,
+        F: FnOnce(&StringServerKey<'_>, &FheString, &RadixCiphertext, &FheAsciiChar) -> FheString,
+    {
+        let fill = FheAsciiChar::new(fill.ciphertext.clone());
+        let inner = with_internal_keys(|keys| {
+            let string_key = string_server_key(keys.integer_key.pbs_key());
+            func(&string_key, &self.inner, &width.ciphertext, &fill)
+        });
+        Self::new(inner)
+    }
+
+    fn compare<F>(&self, rhs: &FheString, func: F) -> FheBool
+    where
+        F: FnOnce(&StringServerKey<'_>, &FheString, &FheString) -> BooleanBlock,
+    {
+        let inner_result = with_internal_keys(|keys| {
+            let string_key = string_server_key(keys.integer_key.pbs_key());
+            func(&string_key, &self.inner, rhs)
+        });
+        FheBool::new(inner_result)
+    }
+
+    fn compare_clear<F>(&self, rhs: &str, func: F) -> FheBool
+    where
+        F: FnOnce(&StringServerKey<'_>, &FheString, &FheString) -> BooleanBlock,
+    {
+        let inner_result = with_internal_keys(|keys| {
+            let string_key = string_server_key(keys.integer_key.pbs_key());
+            let rhs = string_key.create_trivial_ascii(rhs);
+            func(&string_key, &self.inner, &rhs)
+        });
+        FheBool::new(inner_result)
+    }
+}
+
+impl Named for FheAsciiString {
+    const NAME: &'static str = "high_level_api::FheAsciiString";
+}
+
+impl ParameterSetConformant for FheAsciiString {
+    type ParameterSet = FheStringConformanceParams;
+
+    fn is_conformant(&self, params: &FheStringConformanceParams) -> bool {
+        self.inner.is_conformant(params)
+    }
+}
+
+impl FheTryEncrypt<&str, ClientKey> for FheAsciiString {
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(value: &str, key: &ClientKey) -> Result<Self, Self::Error> {
+        if !is_valid_ascii(value) {
+            return Err(OutOfRangeError.into());
+        }
+        let string_client_key = StringClientKey::from(&key.key.key);
+        let inner = string_client_key.encrypt_ascii(value, None);
+        Ok(Self::new(inner))
+    }
+}
+
+impl FheTryEncrypt<&str, PublicKey> for FheAsciiString {
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(value: &str, key: &PublicKey) -> Result<Self, Self::Error> {
+        if !is_valid_ascii(value) {
+            return Err(OutOfRangeError.into());
+        }
+        let public_key = crate::strings::PublicKey::from(&key.key);
+        let inner = public_key.encrypt_ascii(value, None);
+        Ok(Self::new(inner))
+    }
+}
+
+impl FheTryEncrypt<&str, CompressedPublicKey> for FheAsciiString {
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(value: &str, key: &CompressedPublicKey) -> Result<Self, Self::Error> {
+        if !is_valid_ascii(value) {
+            return Err(OutOfRangeError.into());
+        }
+        let public_key = crate::strings::CompressedPublicKey::from(&key.key);
+        let inner = public_key.encrypt_ascii(value, None);
+        Ok(Self::new(inner))
+    }
+}
+
+impl FheTryEncrypt<&str, CompactPublicKey> for FheAsciiString {
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(value: &str, key: &CompactPublicKey) -> Result<Self, Self::Error> {
+        CompactFheAsciiString::try_encrypt(value, key).map(|compact| compact.expand())
+    }
+}
+
+impl FheTryTrivialEncrypt<&str> for FheAsciiString {
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt_trivial(value: &str) -> Result<Self, Self::Error> {
+        if !is_valid_ascii(value) {
+            return Err(OutOfRangeError.into());
+        }
+        let inner = with_internal_keys(|keys| {
+            string_server_key(keys.integer_key.pbs_key()).create_trivial_ascii(value)
+        });
+        Ok(Self::new(inner))
+    }
+}
+
+impl FheTrivialEncrypt<&str> for FheAsciiString {
+    #[track_caller]
+    fn encrypt_trivial(value: &str) -> Self {
+        Self::try_encrypt_trivial(value).unwrap()
+    }
+}
+
+impl FheDecrypt<String> for FheAsciiString {
+    fn decrypt(&self, key: &ClientKey) -> String {
+        StringClientKey::from(&key.key.key).decrypt_ascii(&self.inner)
+    }
+}
+
+#[allow(clippy::redundant_closure_for_method_calls)]
+impl FheEq<Self> for FheAsciiString {
+    fn eq(&self, rhs: Self) -> FheBool {
+        self.compare(&rhs.inner, |key, lhs, rhs| key.eq(lhs, rhs))
+    }
+
+    fn ne(&self, rhs: Self) -> FheBool {
+        self.compare(&rhs.inner, |key, lhs, rhs| key.ne(lhs, rhs))
+    }
+}
+
+#[allow(clippy::redundant_closure_for_method_calls)]
+impl FheEq<&Self> for FheAsciiString {
+    fn eq(&self, rhs: &Self) -> FheBool {
+        self.compare(&rhs.inner, |key, lhs, rhs| key.eq(lhs, rhs))
+    }
+
+    fn ne(&self, rhs: &Self) -> FheBool {
+        self.compare(&rhs.inner, |key, lhs, rhs| key.ne(lhs, rhs))
+    }
+}
+
+#[allow(clippy::redundant_closure_for_method_calls)]
+impl FheEq<&str> for FheAsciiString {
+    fn eq(&self, rhs: &str) -> FheBool {
+        self.compare_clear(rhs, |key, lhs, rhs| key.eq(lhs, rhs))
+    }
+
+    fn ne(&self, rhs: &str) -> FheBool {
+        self.compare_clear(rhs, |key, lhs, rhs| key.ne(lhs, rhs))
+    }
+}
+
+#[allow(clippy::redundant_closure_for_method_calls)]
+impl FheOrd<Self> for FheAsciiString {
+    fn lt(&self, rhs: Self) -> FheBool {
+        self.compare(&rhs.inner, |key, lhs, rhs| key.lt(lhs, rhs))
+    }
+
+    fn le(&self, rhs: Self) -> FheBool {
+        self.compare(&rhs.inner, |key, lhs, rhs| key.le(lhs, rhs))
+    }
+
+    fn gt(&self, rhs: Self) -> FheBool {
+        self.compare(&rhs.inner, |key, lhs, rhs| key.gt(lhs, rhs))
+    }
+
+    fn ge(&self, rhs: Self) -> FheBool {
+        self.compare(&rhs.inner, |key, lhs, rhs| key.ge(lhs, rhs))
+    }
+}
+
+#[allow(clippy::redundant_closure_for_method_calls)]
+impl FheOrd<&Self> for FheAsciiString {
+    fn lt(&self, rhs: &Self) -> FheBool {
+        self.compare(&rhs.inner, |key, lhs, rhs| key.lt(lhs, rhs))
+    }
+
+    fn le(&self, rhs: &Self) -> FheBool {
+        self.compare(&rhs.inner, |key, lhs, rhs| key.le(lhs, rhs))
+    }
+
+    fn gt(&self, rhs: &Self) -> FheBool {
+        self.compare(&rhs.inner, |key, lhs, rhs| key.gt(lhs, rhs))
+    }
+
+    fn ge(&self, rhs: &Self) -> FheBool {
+        self.compare(&rhs.inner, |key, lhs, rhs| key.ge(lhs, rhs))
+    }
+}
+
+#[allow(clippy::redundant_closure_for_method_calls)]
+impl FheOrd<&str> for FheAsciiString {
+    fn lt(&self, rhs: &str) -> FheBool {
+        self.compare_clear(rhs, |key, lhs, rhs| key.lt(lhs, rhs))
+    }
+
+    fn le(&self, rhs: &str) -> FheBool {
+        self.compare_clear(rhs, |key, lhs, rhs| key.le(lhs, rhs))
+    }
+
+    fn gt(&self, rhs: &str) -> FheBool {
+        self.compare_clear(rhs, |key, lhs, rhs| key.gt(lhs, rhs))
+    }
+
+    fn ge(&self, rhs: &str) -> FheBool {
+        self.compare_clear(rhs, |key, lhs, rhs| key.ge(lhs, rhs))
+    }
+}
+
+/// Compressed variant of [FheAsciiString].
+///
+/// It has to be decompressed before doing any computation.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CompressedFheAsciiString {
+    pub(in crate::high_level_api) inner: CompressedFheString,
+}
+
+impl CompressedFheAsciiString {
+    pub fn decompress(self) -> FheAsciiString {
+        FheAsciiString::new(self.inner.decompress())
+    }
+}
+
+impl Named for CompressedFheAsciiString {
+    const NAME: &'static str = "high_level_api::CompressedFheAsciiString";
+}
+
+impl ParameterSetConformant for CompressedFheAsciiString {
+    type ParameterSet = FheStringConformanceParams;
+
+    fn is_conformant(&self, params: &FheStringConformanceParams) -> bool {
+        self.inner.is_conformant(params)
+    }
+}
+
+impl From<CompressedFheAsciiString> for FheAsciiString {
+    fn from(value: CompressedFheAsciiString) -> Self {
+        value.decompress()
+    }
+}
+
+impl FheTryEncrypt<&str, ClientKey> for CompressedFheAsciiString {
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(value: &str, key: &ClientKey) -> Result<Self, Self::Error> {
+        if !is_valid_ascii(value) {
+            return Err(OutOfRangeError.into());
+        }
+        let string_client_key = StringClientKey::from(&key.key.key);
+        let inner = string_client_key.encrypt_ascii_compressed(value, None);
+        Ok(Self { inner })
+    }
+}
+
+/// Compact variant of [FheAsciiString], encrypted using a [CompactPublicKey].
+///
+/// All the characters are packed in a single compact list, to minimize the size of the
+/// ciphertext. It has to be expanded before doing any computation.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CompactFheAsciiString {
+    pub(in crate::high_level_api) inner: CompactFheString,
+}
+
+impl CompactFheAsciiString {
+    /// Encrypts the string, appending `padding` encrypted null characters to hide its length.
+    pub fn try_encrypt_with_padding(
+        value: &str,
+        padding: u32,
+        key: &CompactPublicKey,
+    ) -> Result<Self, crate::high_level_api::errors::Error> {
+        if !is_valid_ascii(value) {
+            return Err(OutOfRangeError.into());
+        }
+        let public_key = crate::strings::CompactPublicKey::from(&key.key.key);
+        let inner = public_key.encrypt_ascii(value, Some(padding));
+        Ok(Self { inner })
+    }
+
+    /// Returns the number of encrypted characters, padding included.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn expand(&self) -> FheAsciiString {
+        FheAsciiString::new(self.inner.expand())
+    }
+}
+
+impl Named for CompactFheAsciiString {
+    const NAME: &'static str = "high_level_api::CompactFheAsciiString";
+}
+
+impl ParameterSetConformant for CompactFheAsciiString {
+    type ParameterSet = FheStringConformanceParams;
+
+    fn is_conformant(&self, params: &FheStringConformanceParams) -> bool {
+        self.inner.is_conformant(params)
+    }
+}
+
+impl FheTryEncrypt<&str, CompactPublicKey> for CompactFheAsciiString {
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(value: &str, key: &CompactPublicKey) -> Result<Self, Self::Error> {
+        if !is_valid_ascii(value) {
+            return Err(OutOfRangeError.into());
+        }
+        let public_key = crate::strings::CompactPublicKey::from(&key.key.key);
+        let inner = public_key.encrypt_ascii(value, None);
+        Ok(Self { inner })
+    }
+}
+
+#[cfg(feature = "safe-deserialization")]
+pub mod safe_serialize {
+    use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
+    use crate::named::Named;
+    use crate::strings::FheStringConformanceParams;
+    use crate::ServerKey;
+    use serde::de::DeserializeOwned;
+
+    /// Deserializes an encrypted string and checks that it is conformant with the given server
+    /// key, its number of characters (padding included) being bounded by `num_chars_constraint`.
+    ///
+    /// This works for [FheAsciiString](super::FheAsciiString) as well as its compressed and compact
+    /// variants.
+    pub fn safe_deserialize_conformant_string<T>(
+        reader: impl std::io::Read,
+        serialized_size_limit: u64,
+        sk: &ServerKey,
+        num_chars_constraint: ListSizeConstraint,
+    ) -> Result<T, String>
+    where
+        T: Named
+            + DeserializeOwned
+            + ParameterSetConformant<ParameterSet = FheStringConformanceParams>,
+    {
+        let parameter_set = crate::strings::ServerKey::new(sk.integer_key.pbs_key())
+            .conformance_params(num_chars_constraint);
+
+        crate::safe_deserialization::safe_deserialize_conformant(
+            reader,
+            serialized_size_limit,
+            &parameter_set,
+        )
+    }
+}
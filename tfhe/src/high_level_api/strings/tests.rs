@@ -0,0 +1,330 @@
+use crate::high_level_api::prelude::*;
+use crate::high_level_api::{
+    generate_keys, set_server_key, set_string_execution_mode, string_execution_mode, ClientKey,
+    CompactFheAsciiString, CompactPublicKey, CompressedFheAsciiString, CompressedPublicKey,
+    ConfigBuilder, FheAsciiString, FheAsciiStringMap, FheUint8,
+};
+use crate::strings::sha256::Adder;
+use crate::strings::ExecutionMode;
+
+fn setup_default() -> ClientKey {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+    client_key
+}
+
+#[test]
+fn test_ascii_string_encrypt_decrypt() {
+    let client_key = setup_default();
+
+    let a = FheAsciiString::encrypt("tfhe-rs", &client_key);
+    assert_eq!(a.len(), 7);
+    assert!(!a.is_padded());
+    let decrypted: String = a.decrypt(&client_key);
+    assert_eq!(decrypted, "tfhe-rs");
+
+    let a = FheAsciiString::try_encrypt_with_padding("tfhe", 3, &client_key).unwrap();
+    assert_eq!(a.len(), 7);
+    assert!(a.is_padded());
+    let decrypted: String = a.decrypt(&client_key);
+    assert_eq!(decrypted, "tfhe");
+
+    let a = FheAsciiString::encrypt_trivial("trivial");
+    let decrypted: String = a.decrypt(&client_key);
+    assert_eq!(decrypted, "trivial");
+
+    assert!(FheAsciiString::try_encrypt("héllo", &client_key).is_err());
+    assert!(FheAsciiString::try_encrypt("a\0b", &client_key).is_err());
+}
+
+#[test]
+fn test_ascii_string_compressed() {
+    let client_key = setup_default();
+
+    let compressed = CompressedFheAsciiString::encrypt("compressed", &client_key);
+    let a = compressed.decompress();
+    let decrypted: String = a.decrypt(&client_key);
+    assert_eq!(decrypted, "compressed");
+}
+
+#[test]
+fn test_ascii_string_compact() {
+    let config = ConfigBuilder::default()
+        .use_custom_parameters(
+            crate::shortint::parameters::parameters_compact_pk::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+            None,
+        )
+        .build();
+    let (client_key, _) = generate_keys(config);
+
+    let public_key = CompactPublicKey::new(&client_key);
+
+    let compact = CompactFheAsciiString::encrypt("compact", &public_key);
+    let a = compact.expand();
+    let decrypted: String = a.decrypt(&client_key);
+    assert_eq!(decrypted, "compact");
+
+    let compact = CompactFheAsciiString::try_encrypt_with_padding("", 4, &public_key).unwrap();
+    assert_eq!(compact.len(), 4);
+    let a = compact.expand();
+    assert!(a.is_padded());
+    let decrypted: String = a.decrypt(&client_key);
+    assert_eq!(decrypted, "");
+
+    let a = FheAsciiString::encrypt("expanded", &public_key);
+    let decrypted: String = a.decrypt(&client_key);
+    assert_eq!(decrypted, "expanded");
+}
+
+#[test]
+fn test_ascii_string_public_key() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, _) = generate_keys(config);
+
+    let compressed_public_key = CompressedPublicKey::new(&client_key);
+
+    let a = FheAsciiString::encrypt("ab", &compressed_public_key);
+    let decrypted: String = a.decrypt(&client_key);
+    assert_eq!(decrypted, "ab");
+
+    let public_key = compressed_public_key.decompress();
+
+    let a = FheAsciiString::encrypt("cd", &public_key);
+    let decrypted: String = a.decrypt(&client_key);
+    assert_eq!(decrypted, "cd");
+
+    assert!(FheAsciiString::try_encrypt("é", &public_key).is_err());
+}
+
+#[test]
+fn test_ascii_string_comparisons() {
+    let client_key = setup_default();
+
+    let a = FheAsciiString::try_encrypt_with_padding("abc", 2, &client_key).unwrap();
+    let b = FheAsciiString::encrypt("abd", &client_key);
+
+    assert!(!a.eq(&b).decrypt(&client_key));
+    assert!(a.ne(&b).decrypt(&client_key));
+    assert!(a.lt(&b).decrypt(&client_key));
+    assert!(a.le(&b).decrypt(&client_key));
+    assert!(!a.gt(&b).decrypt(&client_key));
+    assert!(!a.ge(&b).decrypt(&client_key));
+
+    assert!(a.eq("abc").decrypt(&client_key));
+    assert!(a.gt("ab").decrypt(&client_key));
+    assert!(a.le("b").decrypt(&client_key));
+    assert!(b.ge(a).decrypt(&client_key));
+}
+
+#[test]
+fn test_ascii_string_execution_modes() {
+    let client_key = setup_default();
+
+    assert_eq!(string_execution_mode(), ExecutionMode::ConstantTime);
+
+    let a = FheAsciiString::try_encrypt_with_padding("tfhe", 2, &client_key).unwrap();
+    let b = FheAsciiString::encrypt_trivial("tfhe-rs");
+    let (start, end) = (
+        FheUint8::encrypt_trivial(1u8),
+        FheUint8::encrypt_trivial(3u8),
+    );
+
+    for mode in [ExecutionMode::ConstantTime, ExecutionMode::Optimized] {
+        set_string_execution_mode(mode);
+        assert_eq!(string_execution_mode(), mode);
+
+        assert!(
+            a.lt(&b).decrypt(&client_key),
+            "Invalid result in {mode:?} mode"
+        );
+        assert!(
+            b.gt("tfhe").decrypt(&client_key),
+            "Invalid result in {mode:?} mode"
+        );
+
+        let substring = a.substring(&start, &end);
+        let decrypted: String = substring.decrypt(&client_key);
+        assert_eq!(decrypted, "fh", "Invalid substring in {mode:?} mode");
+
+        // Only the optimized mode uses the trivial indices to shorten the result
+        let expected_len = match mode {
+            ExecutionMode::ConstantTime => a.len(),
+            ExecutionMode::Optimized => 2,
+        };
+        assert_eq!(substring.len(), expected_len);
+    }
+
+    set_string_execution_mode(ExecutionMode::ConstantTime);
+}
+
+#[test]
+fn test_ascii_string_char_at_and_substring() {
+    let client_key = setup_default();
+
+    let a = FheAsciiString::try_encrypt_with_padding("fhe", 1, &client_key).unwrap();
+    let start = FheUint8::encrypt(1u8, &client_key);
+    let end = FheUint8::encrypt(3u8, &client_key);
+
+    let char: u8 = a.char_at(&start).decrypt(&client_key);
+    assert_eq!(char, b'h');
+    let char: u8 = a
+        .char_at(&FheUint8::encrypt(5u8, &client_key))
+        .decrypt(&client_key);
+    assert_eq!(char, 0);
+
+    let substring = a.substring(&start, &end);
+    assert_eq!(substring.len(), 4);
+    let decrypted: String = substring.decrypt(&client_key);
+    assert_eq!(decrypted, "he");
+}
+
+#[test]
+fn test_ascii_string_sha256() {
+    let client_key = setup_default();
+
+    // A trivial string keeps the test fast
+    let a = FheAsciiString::encrypt_trivial("abc");
+
+    for adder in [Adder::BrentKung, Adder::LadnerFischer] {
+        let digest = a
+            .sha256(adder)
+            .iter()
+            .map(|word| {
+                let word: u32 = word.decrypt(&client_key);
+                format!("{word:08x}")
+            })
+            .collect::<Vec<_>>()
+            .concat();
+        assert_eq!(
+            digest, "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            "Invalid digest using {adder:?}"
+        );
+    }
+}
+
+#[test]
+fn test_ascii_string_encodings() {
+    let client_key = setup_default();
+
+    let a = FheAsciiString::try_encrypt_with_padding("Ma", 1, &client_key).unwrap();
+
+    let hex = a.hex_encode();
+    let decrypted: String = hex.decrypt(&client_key);
+    assert_eq!(decrypted, "4d61");
+
+    let (decoded, is_valid) = hex.hex_decode();
+    assert!(is_valid.decrypt(&client_key));
+    let decrypted: String = decoded.decrypt(&client_key);
+    assert_eq!(decrypted, "Ma");
+
+    let base64 = a.base64_encode();
+    let decrypted: String = base64.decrypt(&client_key);
+    assert_eq!(decrypted, "TWE=");
+
+    let (decoded, is_valid) = base64.base64_decode();
+    assert!(is_valid.decrypt(&client_key));
+    let decrypted: String = decoded.decrypt(&client_key);
+    assert_eq!(decrypted, "Ma");
+
+    let (decoded, is_valid) = FheAsciiString::encrypt_trivial("TWE").base64_decode();
+    assert!(!is_valid.decrypt(&client_key));
+    let decrypted: String = decoded.decrypt(&client_key);
+    assert_eq!(decrypted, "");
+}
+
+#[test]
+fn test_ascii_string_map() {
+    let client_key = setup_default();
+
+    let mut map = FheAsciiStringMap::new();
+    map.insert(
+        FheAsciiString::encrypt_trivial("alice"),
+        FheUint8::encrypt(12u8, &client_key),
+    );
+    map.insert(
+        FheAsciiString::encrypt("bob", &client_key),
+        FheUint8::encrypt(34u8, &client_key),
+    );
+
+    let query = FheAsciiString::try_encrypt_with_padding("alice", 1, &client_key).unwrap();
+    let (value, found) = map.get(&query);
+    assert!(found.decrypt(&client_key));
+    let value: u8 = value.decrypt(&client_key);
+    assert_eq!(value, 12);
+
+    let query = FheAsciiString::encrypt("carol", &client_key);
+    let (value, found) = map.get(&query);
+    assert!(!found.decrypt(&client_key));
+    let value: u8 = value.decrypt(&client_key);
+    assert_eq!(value, 0);
+}
+
+#[test]
+fn test_ascii_string_sort() {
+    let client_key = setup_default();
+
+    let strs = [
+        FheAsciiString::try_encrypt_with_padding("bob", 1, &client_key).unwrap(),
+        FheAsciiString::encrypt("alice", &client_key),
+        FheAsciiString::encrypt_trivial("bo"),
+    ];
+
+    let sorted = FheAsciiString::sort(&strs);
+    let decrypted: Vec<String> = sorted.iter().map(|str| str.decrypt(&client_key)).collect();
+    assert_eq!(decrypted, ["alice", "bo", "bob"]);
+
+    // Hexadecimal keys keep the order
+    let sorted = FheAsciiString::sort_by_key(&strs, FheAsciiString::hex_encode);
+    let decrypted: Vec<String> = sorted.iter().map(|str| str.decrypt(&client_key)).collect();
+    assert_eq!(decrypted, ["alice", "bo", "bob"]);
+}
+
+#[test]
+fn test_ascii_string_pad() {
+    let client_key = setup_default();
+
+    let a = FheAsciiString::try_encrypt_with_padding("42", 1, &client_key).unwrap();
+    let width = FheUint8::encrypt(4u8, &client_key);
+
+    let padded = a.pad_start(&width, &FheUint8::encrypt_trivial(b'0'), 6);
+    assert_eq!(padded.len(), 6);
+    let decrypted: String = padded.decrypt(&client_key);
+    assert_eq!(decrypted, "0042");
+
+    let padded = a.pad_end(&width, &FheUint8::encrypt(b'.', &client_key), 6);
+    let decrypted: String = padded.decrypt(&client_key);
+    assert_eq!(decrypted, "42..");
+}
+
+#[test]
+fn test_ascii_string_tokenize() {
+    let client_key = setup_default();
+
+    let a = FheAsciiString::try_encrypt_with_padding("no, yes", 1, &client_key).unwrap();
+    let tokens = a.tokenize(&["yes", "no"], 3);
+
+    let decrypted: Vec<(u16, bool)> = tokens
+        .iter()
+        .map(|(id, is_present)| (id.decrypt(&client_key), is_present.decrypt(&client_key)))
+        .collect();
+    assert_eq!(decrypted, [(1, true), (0, true), (0, false)]);
+}
+
+#[test]
+fn test_ascii_string_find() {
+    let client_key = setup_default();
+
+    let a = FheAsciiString::try_encrypt_with_padding("abcab", 1, &client_key).unwrap();
+    let b = FheAsciiString::encrypt("ca", &client_key);
+
+    assert!(a.contains(&b).decrypt(&client_key));
+    assert!(!a.starts_with(&b).decrypt(&client_key));
+
+    let (index, found) = a.find(&b);
+    assert!(found.decrypt(&client_key));
+    let index: u16 = index.decrypt(&client_key);
+    assert_eq!(index, 2);
+}
@@ -68,7 +68,7 @@ where
     }
 
     let sign_bit_pos = num_bits_set - 1;
-    let sign_bit_mask = T::cast_from(1u32 << sign_bit_pos);
+    let sign_bit_mask = T::cast_from(1u32) << sign_bit_pos;
     let sign_bit = (unpadded_value & sign_bit_mask) >> sign_bit_pos;
 
     // Creates a padding mask
@@ -0,0 +1,250 @@
+use crate::integer::ciphertext::{CrtCiphertext, RadixCiphertext};
+use crate::integer::{BooleanBlock, ServerKey};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes homomorphically whether two ciphertexts in the CRT decomposition encrypt the
+    /// same value.
+    ///
+    /// The comparison is made block by block: two values are equal if and only if all their
+    /// residues are.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_crt;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let basis = vec![2, 3, 5];
+    /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS, basis);
+    ///
+    /// let ctxt_1 = cks.encrypt(14);
+    /// let ctxt_2 = cks.encrypt(14);
+    ///
+    /// let ct_res = sks.crt_eq_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.as_ref().decrypt_bool(&ct_res);
+    /// assert!(res);
+    /// ```
+    pub fn crt_eq_parallelized(
+        &self,
+        ct_left: &CrtCiphertext,
+        ct_right: &CrtCiphertext,
+    ) -> BooleanBlock {
+        assert_eq!(
+            ct_left.moduli, ct_right.moduli,
+            "The ciphertexts must use the same moduli"
+        );
+
+        let block_comparisons = ct_left
+            .blocks
+            .par_iter()
+            .zip(ct_right.blocks.par_iter())
+            .zip(ct_left.moduli.par_iter())
+            .map(|((left, right), &modulus)| {
+                // left - right is computed as left + (modulus - right % modulus)
+                let negate = self
+                    .key
+                    .generate_lookup_table(|x| (modulus - x % modulus) % modulus);
+                let mut difference = self.key.apply_lookup_table(right, &negate);
+                if self
+                    .key
+                    .is_add_possible(left.noise_degree(), difference.noise_degree())
+                    .is_ok()
+                {
+                    self.key.unchecked_add_assign(&mut difference, left);
+                } else {
+                    let reduce = self.key.generate_lookup_table(|x| x % modulus);
+                    let left = self.key.apply_lookup_table(left, &reduce);
+                    self.key.unchecked_add_assign(&mut difference, &left);
+                }
+
+                let is_zero = self
+                    .key
+                    .generate_lookup_table(|x| u64::from(x % modulus == 0));
+                let mut is_equal = self.key.apply_lookup_table(&difference, &is_zero);
+                is_equal.message_modulus = self.key.message_modulus;
+                is_equal.carry_modulus = self.key.carry_modulus;
+                is_equal
+            })
+            .collect::<Vec<_>>();
+
+        BooleanBlock::new_unchecked(self.are_all_comparisons_block_true(block_comparisons))
+    }
+
+    /// Computes homomorphically whether the value encrypted by `ct_left` is lower than the one
+    /// encrypted by `ct_right`.
+    ///
+    /// The values are compared as integers in `[0, M)`, `M` being the product of the moduli. As
+    /// the order is not compatible with the CRT decomposition, both ciphertexts are converted
+    /// to radix ciphertexts beforehand.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_crt;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let basis = vec![2, 3, 5];
+    /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS, basis);
+    ///
+    /// let ctxt_1 = cks.encrypt(14);
+    /// let ctxt_2 = cks.encrypt(23);
+    ///
+    /// let ct_res = sks.crt_lt_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.as_ref().decrypt_bool(&ct_res);
+    /// assert!(res);
+    /// ```
+    pub fn crt_lt_parallelized(
+        &self,
+        ct_left: &CrtCiphertext,
+        ct_right: &CrtCiphertext,
+    ) -> BooleanBlock {
+        let (left, right) = self.crt_pair_to_radix(ct_left, ct_right);
+        self.lt_parallelized(&left, &right)
+    }
+
+    /// Computes homomorphically whether the value encrypted by `ct_left` is lower than or equal
+    /// to the one encrypted by `ct_right`.
+    ///
+    /// See [Self::crt_lt_parallelized].
+    pub fn crt_le_parallelized(
+        &self,
+        ct_left: &CrtCiphertext,
+        ct_right: &CrtCiphertext,
+    ) -> BooleanBlock {
+        let (left, right) = self.crt_pair_to_radix(ct_left, ct_right);
+        self.le_parallelized(&left, &right)
+    }
+
+    /// Computes homomorphically whether the value encrypted by `ct_left` is greater than the
+    /// one encrypted by `ct_right`.
+    ///
+    /// See [Self::crt_lt_parallelized].
+    pub fn crt_gt_parallelized(
+        &self,
+        ct_left: &CrtCiphertext,
+        ct_right: &CrtCiphertext,
+    ) -> BooleanBlock {
+        let (left, right) = self.crt_pair_to_radix(ct_left, ct_right);
+        self.gt_parallelized(&left, &right)
+    }
+
+    /// Computes homomorphically whether the value encrypted by `ct_left` is greater than or
+    /// equal to the one encrypted by `ct_right`.
+    ///
+    /// See [Self::crt_lt_parallelized].
+    pub fn crt_ge_parallelized(
+        &self,
+        ct_left: &CrtCiphertext,
+        ct_right: &CrtCiphertext,
+    ) -> BooleanBlock {
+        let (left, right) = self.crt_pair_to_radix(ct_left, ct_right);
+        self.ge_parallelized(&left, &right)
+    }
+
+    /// Computes homomorphically the minimum of two ciphertexts in the CRT decomposition.
+    ///
+    /// See [Self::crt_lt_parallelized] for the order of the values.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_crt;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let basis = vec![2, 3, 5];
+    /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS, basis);
+    ///
+    /// let ctxt_1 = cks.encrypt(14);
+    /// let ctxt_2 = cks.encrypt(23);
+    ///
+    /// let ct_res = sks.crt_min_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 14);
+    /// ```
+    pub fn crt_min_parallelized(
+        &self,
+        ct_left: &CrtCiphertext,
+        ct_right: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        let (left, right) = self.crt_pair_to_radix(ct_left, ct_right);
+        let min = self.min_parallelized(&left, &right);
+        self.radix_to_crt(&min, &ct_left.moduli)
+    }
+
+    /// Computes homomorphically the maximum of two ciphertexts in the CRT decomposition.
+    ///
+    /// See [Self::crt_min_parallelized].
+    pub fn crt_max_parallelized(
+        &self,
+        ct_left: &CrtCiphertext,
+        ct_right: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        let (left, right) = self.crt_pair_to_radix(ct_left, ct_right);
+        let max = self.max_parallelized(&left, &right);
+        self.radix_to_crt(&max, &ct_left.moduli)
+    }
+
+    /// Computes homomorphically the quotient and remainder of the division of two ciphertexts
+    /// in the CRT decomposition.
+    ///
+    /// The values are divided as integers in `[0, M)`, `M` being the product of the moduli.
+    /// The result of a division by zero is unspecified.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_crt;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let basis = vec![2, 3, 5];
+    /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS, basis);
+    ///
+    /// let ctxt_1 = cks.encrypt(29);
+    /// let ctxt_2 = cks.encrypt(4);
+    ///
+    /// let (q, r) = sks.crt_div_rem_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// assert_eq!(cks.decrypt(&q), 7);
+    /// assert_eq!(cks.decrypt(&r), 1);
+    /// ```
+    pub fn crt_div_rem_parallelized(
+        &self,
+        ct_left: &CrtCiphertext,
+        ct_right: &CrtCiphertext,
+    ) -> (CrtCiphertext, CrtCiphertext) {
+        let (left, right) = self.crt_pair_to_radix(ct_left, ct_right);
+        let (quotient, remainder) = self.div_rem_parallelized(&left, &right);
+        rayon::join(
+            || self.radix_to_crt(&quotient, &ct_left.moduli),
+            || self.radix_to_crt(&remainder, &ct_left.moduli),
+        )
+    }
+
+    fn crt_pair_to_radix(
+        &self,
+        ct_left: &CrtCiphertext,
+        ct_right: &CrtCiphertext,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        assert_eq!(
+            ct_left.moduli, ct_right.moduli,
+            "The ciphertexts must use the same moduli"
+        );
+
+        rayon::join(
+            || self.crt_to_radix(ct_left),
+            || self.crt_to_radix(ct_right),
+        )
+    }
+}
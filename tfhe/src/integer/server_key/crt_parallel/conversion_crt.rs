@@ -0,0 +1,212 @@
+use crate::integer::ciphertext::{CrtCiphertext, IntegerCiphertext, RadixCiphertext};
+use crate::integer::ServerKey;
+use crate::shortint::{CarryModulus, Ciphertext, MessageModulus};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Converts a ciphertext in the CRT decomposition into a radix ciphertext.
+    ///
+    /// The value `x` is recomposed as `x = sum(x_i * e_i) mod M`, `e_i` being the CRT basis
+    /// element of the `i`-th modulus and `M` the product of the moduli. Each term of the sum is
+    /// computed as a radix ciphertext from the `i`-th block with one lookup table per radix
+    /// block, and the sum is reduced modulo `M` with conditional subtractions.
+    ///
+    /// The result has just enough blocks to encrypt values below `M`.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_crt;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let basis = vec![2, 3, 5];
+    /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS, basis);
+    ///
+    /// let clear = 23u64;
+    /// let ctxt = cks.encrypt(clear);
+    ///
+    /// let ct_res = sks.crt_to_radix(&ctxt);
+    ///
+    /// // Decrypt
+    /// let res: u64 = cks.as_ref().decrypt_radix(&ct_res);
+    /// assert_eq!(res, clear);
+    /// ```
+    pub fn crt_to_radix(&self, ct: &CrtCiphertext) -> RadixCiphertext {
+        let modulus = crt_modulus(&ct.moduli);
+        let num_blocks = self.num_radix_blocks_below(modulus);
+        // The sum of the terms is below moduli.len() * modulus
+        let sum_num_blocks = self.num_radix_blocks_below(modulus * ct.moduli.len() as u128);
+
+        let bits_per_block = self.key.message_modulus.0.ilog2();
+        let message_modulus = self.key.message_modulus.0 as u128;
+
+        let terms = ct
+            .blocks
+            .par_iter()
+            .zip(ct.moduli.par_iter())
+            .map(|(block, &block_modulus)| {
+                let basis_element = crt_basis_element(&ct.moduli, block_modulus);
+                let term = |x: u64| u128::from(x % block_modulus) * basis_element % modulus;
+
+                let mut blocks = (0..num_blocks)
+                    .into_par_iter()
+                    .map(|j| {
+                        let lut = self.key.generate_lookup_table(|x| {
+                            ((term(x) >> (j as u32 * bits_per_block)) % message_modulus) as u64
+                        });
+                        let mut radix_block = self.key.apply_lookup_table(block, &lut);
+                        radix_block.message_modulus = self.key.message_modulus;
+                        radix_block.carry_modulus = self.key.carry_modulus;
+                        radix_block
+                    })
+                    .collect::<Vec<_>>();
+                blocks.resize_with(sum_num_blocks, || self.key.create_trivial(0));
+
+                RadixCiphertext::from_blocks(blocks)
+            })
+            .collect::<Vec<_>>();
+
+        let sum = self
+            .sum_ciphertexts_parallelized(&terms)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(sum_num_blocks));
+
+        // The sum is below 2^k * M, k being the number of bits of moduli.len() - 1, the
+        // multiples 2^i * M are subtracted when they are not greater than it, from i = k - 1
+        let num_reductions = usize::BITS - (ct.moduli.len() - 1).leading_zeros();
+        let mut result = sum;
+        for i in (0..num_reductions).rev() {
+            let multiple = modulus << i;
+            let (is_ge, difference) = rayon::join(
+                || self.scalar_ge_parallelized(&result, multiple),
+                || self.scalar_sub_parallelized(&result, multiple),
+            );
+            result = self.if_then_else_parallelized(&is_ge, &difference, &result);
+        }
+        self.trim_radix_blocks_msb_assign(&mut result, sum_num_blocks - num_blocks);
+        result
+    }
+
+    /// Converts a radix ciphertext into a ciphertext in the CRT decomposition with the given
+    /// `moduli`, the result encrypting the value of `ct` modulo the product of the moduli.
+    ///
+    /// The residue modulo `m` is computed as `sum(d_j * (B^j mod m)) mod m`, `d_j` being the
+    /// radix blocks and `B` the message modulus: each term is computed with a lookup table, and
+    /// the terms are accumulated in the carry space of the block, which is reduced modulo `m`
+    /// when it would overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a modulus is not in `[2, message_modulus * carry_modulus / 2]`.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 3;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_3_CARRY_3_KS_PBS, num_blocks);
+    ///
+    /// let basis = [2, 3, 5, 7];
+    /// let clear = 345u64;
+    /// let ctxt = cks.encrypt(clear);
+    ///
+    /// let ct_res = sks.radix_to_crt(&ctxt, &basis);
+    ///
+    /// // Decrypt
+    /// let res = cks.as_ref().decrypt_crt(&ct_res);
+    /// assert_eq!(res, clear % 210);
+    /// ```
+    pub fn radix_to_crt(&self, ct: &RadixCiphertext, moduli: &[u64]) -> CrtCiphertext {
+        let total_modulus = (self.key.message_modulus.0 * self.key.carry_modulus.0) as u64;
+        for &modulus in moduli {
+            assert!(
+                modulus >= 2 && 2 * modulus <= total_modulus,
+                "The moduli must be between 2 and half of the product of the message and \
+                carry moduli ({total_modulus}), got {modulus}"
+            );
+        }
+
+        let mut cleaned;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            cleaned = ct.clone();
+            self.full_propagate_parallelized(&mut cleaned);
+            &cleaned
+        };
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+
+        let blocks = moduli
+            .par_iter()
+            .map(|&modulus| {
+                let reduce = self.key.generate_lookup_table(|x| x % modulus);
+
+                let terms = ct
+                    .blocks()
+                    .par_iter()
+                    .enumerate()
+                    .map(|(j, block)| {
+                        let weight = pow_mod(message_modulus, j as u64, modulus);
+                        let lut = self
+                            .key
+                            .generate_lookup_table(|x| (x % message_modulus) * weight % modulus);
+                        self.key.apply_lookup_table(block, &lut)
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut residue: Ciphertext = self.key.create_trivial(0);
+                for term in &terms {
+                    if self
+                        .key
+                        .is_add_possible(residue.noise_degree(), term.noise_degree())
+                        .is_err()
+                    {
+                        self.key.apply_lookup_table_assign(&mut residue, &reduce);
+                    }
+                    self.key.unchecked_add_assign(&mut residue, term);
+                }
+                if residue.degree.get() >= modulus as usize {
+                    self.key.apply_lookup_table_assign(&mut residue, &reduce);
+                }
+
+                residue.message_modulus = MessageModulus(modulus as usize);
+                residue.carry_modulus = CarryModulus(total_modulus as usize / modulus as usize);
+                residue
+            })
+            .collect();
+
+        CrtCiphertext::from((blocks, moduli.to_vec()))
+    }
+
+    /// Returns the number of radix blocks needed to encrypt values below `bound`.
+    fn num_radix_blocks_below(&self, bound: u128) -> usize {
+        let bits_per_block = self.key.message_modulus.0.ilog2();
+        let num_bits = (u128::BITS - (bound - 1).leading_zeros()).max(1);
+        ((num_bits + bits_per_block - 1) / bits_per_block) as usize
+    }
+}
+
+/// Returns the product of the moduli.
+fn crt_modulus(moduli: &[u64]) -> u128 {
+    moduli.iter().copied().map(u128::from).product()
+}
+
+/// Returns the CRT basis element of `modulus`, that is the value congruent to 1 modulo
+/// `modulus` and to 0 modulo the other moduli.
+fn crt_basis_element(moduli: &[u64], modulus: u64) -> u128 {
+    let cofactor = crt_modulus(moduli) / u128::from(modulus);
+    let cofactor_residue = (cofactor % u128::from(modulus)) as u64;
+    let inverse = (1..modulus)
+        .find(|x| x * cofactor_residue % modulus == 1)
+        .unwrap_or_else(|| panic!("The moduli must be pairwise coprime, got {moduli:?}"));
+    cofactor * u128::from(inverse)
+}
+
+/// Returns `base^exponent mod modulus`.
+fn pow_mod(base: u64, exponent: u64, modulus: u64) -> u64 {
+    (0..exponent).fold(1 % modulus, |acc, _| acc * base % modulus)
+}
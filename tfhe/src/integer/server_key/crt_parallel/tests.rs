@@ -229,3 +229,146 @@ fn integer_unchecked_crt_scalar_sub_parallelized_32_bits() {
         );
     }
 }
+
+/// Number of loop iteration within randomized tests of operations converting the ciphertexts
+/// to radix
+const NB_TESTS_SMALLER: usize = 3;
+
+#[test]
+fn integer_crt_comparisons_parallelized() {
+    // The ordered comparisons go through radix ciphertexts, and the conversions need carries
+    // at least as large as the messages
+    let param = PARAM_MESSAGE_3_CARRY_3_KS_PBS;
+
+    // Define CRT basis, and global modulus
+    let basis = [2u64, 3, 5, 7];
+    let modulus = basis.iter().product::<u64>();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::CRT);
+    let mut rng = rand::thread_rng();
+
+    let mut clears = (0..NB_TESTS_SMALLER)
+        .map(|_| (rng.gen::<u64>() % modulus, rng.gen::<u64>() % modulus))
+        .collect::<Vec<_>>();
+    clears.push((modulus - 1, modulus - 1));
+
+    for (clear_0, clear_1) in clears {
+        // The blocks of the first ciphertext are not reduced
+        let clear_to_add = rng.gen::<u64>() % modulus;
+        let ct_zero = sks.unchecked_crt_add_parallelized(
+            &cks.encrypt_crt(clear_0, basis.to_vec()),
+            &cks.encrypt_crt(clear_to_add, basis.to_vec()),
+        );
+        let clear_0 = (clear_0 + clear_to_add) % modulus;
+        let ct_one = cks.encrypt_crt(clear_1, basis.to_vec());
+
+        let ct_res = sks.crt_eq_parallelized(&ct_zero, &ct_one);
+        assert_eq!(cks.decrypt_bool(&ct_res), clear_0 == clear_1);
+
+        let ct_res = sks.crt_lt_parallelized(&ct_zero, &ct_one);
+        assert_eq!(cks.decrypt_bool(&ct_res), clear_0 < clear_1);
+
+        let ct_res = sks.crt_le_parallelized(&ct_zero, &ct_one);
+        assert_eq!(cks.decrypt_bool(&ct_res), clear_0 <= clear_1);
+
+        let ct_res = sks.crt_gt_parallelized(&ct_zero, &ct_one);
+        assert_eq!(cks.decrypt_bool(&ct_res), clear_0 > clear_1);
+
+        let ct_res = sks.crt_ge_parallelized(&ct_zero, &ct_one);
+        assert_eq!(cks.decrypt_bool(&ct_res), clear_0 >= clear_1);
+
+        let ct_res = sks.crt_min_parallelized(&ct_zero, &ct_one);
+        assert_eq!(cks.decrypt_crt(&ct_res), clear_0.min(clear_1));
+
+        let ct_res = sks.crt_max_parallelized(&ct_zero, &ct_one);
+        assert_eq!(cks.decrypt_crt(&ct_res), clear_0.max(clear_1));
+    }
+}
+
+#[test]
+fn integer_crt_div_rem_parallelized() {
+    let param = PARAM_MESSAGE_3_CARRY_3_KS_PBS;
+
+    // Define CRT basis, and global modulus
+    let basis = [2u64, 3, 5, 7];
+    let modulus = basis.iter().product::<u64>();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::CRT);
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen_range(1..modulus);
+
+        let ct_zero = cks.encrypt_crt(clear_0, basis.to_vec());
+        let ct_one = cks.encrypt_crt(clear_1, basis.to_vec());
+
+        let (q_res, r_res) = sks.crt_div_rem_parallelized(&ct_zero, &ct_one);
+        assert_eq!(cks.decrypt_crt(&q_res), clear_0 / clear_1);
+        assert_eq!(cks.decrypt_crt(&r_res), clear_0 % clear_1);
+    }
+}
+
+#[test]
+fn integer_crt_radix_conversions() {
+    // The radix conversions need carries at least as large as the messages
+    let param = PARAM_MESSAGE_3_CARRY_3_KS_PBS;
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::CRT);
+    let mut rng = rand::thread_rng();
+
+    for basis in [vec![2u64, 3, 5, 7], vec![7, 8, 9], vec![13, 31]] {
+        let modulus = basis.iter().product::<u64>();
+
+        let mut clears = (0..NB_TESTS_SMALLER)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        clears.push(modulus - 1);
+
+        for clear in clears {
+            // crt -> radix
+            let ct = cks.encrypt_crt(clear, basis.clone());
+            let ct_radix = sks.crt_to_radix(&ct);
+            let dec: u64 = cks.decrypt_radix(&ct_radix);
+            assert_eq!(dec, clear);
+
+            // radix -> crt, the radix ciphertext is larger than the CRT modulus and its
+            // carries are not empty
+            let clear_0 = rng.gen::<u64>() % (1 << 20);
+            let clear_1 = rng.gen::<u64>() % (1 << 20);
+            let ct_radix = sks.unchecked_add(
+                &cks.encrypt_radix(clear_0, 7),
+                &cks.encrypt_radix(clear_1, 7),
+            );
+            let ct_res = sks.radix_to_crt(&ct_radix, &basis);
+            assert_eq!(cks.decrypt_crt(&ct_res), (clear_0 + clear_1) % modulus);
+
+            // round trip
+            let ct_res = sks.radix_to_crt(&sks.crt_to_radix(&ct), &basis);
+            assert_eq!(cks.decrypt_crt(&ct_res), clear);
+        }
+    }
+}
+
+#[test]
+fn integer_crt_radix_to_crt_many_blocks() {
+    // With a modulus of 2, the terms of the blocks have a degree of 1, so the sum of the terms
+    // of a wide radix ciphertext reaches the noise limit before the degree limit
+    let param = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::CRT);
+    let mut rng = rand::thread_rng();
+
+    let basis = vec![2u64, 3, 5, 7];
+    let modulus = basis.iter().product::<u64>();
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear = rng.gen::<u32>();
+        let ct_radix = cks.encrypt_radix(clear, 16);
+        let ct_res = sks.radix_to_crt(&ct_radix, &basis);
+        assert_eq!(cks.decrypt_crt(&ct_res), u64::from(clear) % modulus);
+        for block in &ct_res.blocks {
+            assert!(sks
+                .key
+                .max_noise_level
+                .validate(block.noise_level())
+                .is_ok());
+        }
+    }
+}
@@ -98,6 +98,34 @@ impl ServerKey {
         )
     }
 
+    /// Creates a trivial ciphertext encrypting the maximum value of the integer type.
+    pub(crate) fn create_trivial_max_radix<T>(&self, num_blocks: usize) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let mut blocks = vec![self.key.create_trivial(message_modulus - 1); num_blocks];
+        if T::IS_SIGNED {
+            // The sign bit is zero
+            blocks[num_blocks - 1] = self.key.create_trivial(message_modulus / 2 - 1);
+        }
+        T::from_blocks(blocks)
+    }
+
+    /// Creates a trivial ciphertext encrypting the minimum value of the integer type.
+    pub(crate) fn create_trivial_min_radix<T>(&self, num_blocks: usize) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut blocks = vec![self.key.create_trivial(0); num_blocks];
+        if T::IS_SIGNED {
+            // Only the sign bit is one
+            let message_modulus = self.key.message_modulus.0 as u64;
+            blocks[num_blocks - 1] = self.key.create_trivial(message_modulus / 2);
+        }
+        T::from_blocks(blocks)
+    }
+
     /// Prepend trivial zero LSB blocks to an existing [`RadixCiphertext`]. This can be useful for
     /// casting operations.
     ///
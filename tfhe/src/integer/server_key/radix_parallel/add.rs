@@ -1,4 +1,4 @@
-use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::ciphertext::{IntegerCiphertext, IntegerRadixCiphertext};
 use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
 use crate::shortint::Ciphertext;
 
@@ -403,6 +403,27 @@ impl ServerKey {
         }
     }
 
+    /// Computes the addition of two ciphertexts and returns the overflow flag
+    ///
+    /// See [Self::unsigned_overflowing_add_parallelized] and
+    /// [Self::signed_overflowing_add_parallelized].
+    pub fn overflowing_add_parallelized<T>(&self, ct_left: &T, ct_right: &T) -> (T, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if T::IS_SIGNED {
+            let lhs = SignedRadixCiphertext::from_blocks(ct_left.blocks().to_vec());
+            let rhs = SignedRadixCiphertext::from_blocks(ct_right.blocks().to_vec());
+            let (result, overflowed) = self.signed_overflowing_add_parallelized(&lhs, &rhs);
+            (T::from_blocks(result.into_blocks()), overflowed)
+        } else {
+            let lhs = RadixCiphertext::from_blocks(ct_left.blocks().to_vec());
+            let rhs = RadixCiphertext::from_blocks(ct_right.blocks().to_vec());
+            let (result, overflowed) = self.unsigned_overflowing_add_parallelized(&lhs, &rhs);
+            (T::from_blocks(result.into_blocks()), overflowed)
+        }
+    }
+
     pub fn add_parallelized_work_efficient<T>(&self, ct_left: &T, ct_right: &T) -> T
     where
         T: IntegerRadixCiphertext,
@@ -877,13 +898,14 @@ impl ServerKey {
                         },
                         || {
                             let start = first_block_where_addition_happened;
+                            // end is exclusive, so that single block ciphertexts get no carry
                             let end = if last_block_where_addition_happened == num_blocks - 1 {
                                 // This carry would be thrown away, so don't compute it
-                                last_block_where_addition_happened - 1
-                            } else {
                                 last_block_where_addition_happened
+                            } else {
+                                last_block_where_addition_happened + 1
                             };
-                            carry_ct.blocks_mut()[start..=end]
+                            carry_ct.blocks_mut()[start..end]
                                 .par_iter_mut()
                                 .for_each(|block| {
                                     self.key.carry_extract_assign(block);
@@ -891,7 +913,7 @@ impl ServerKey {
                             for block in &mut carry_ct.blocks_mut()[..start] {
                                 self.key.create_trivial_assign(block, 0);
                             }
-                            for block in &mut carry_ct.blocks_mut()[end + 1..] {
+                            for block in &mut carry_ct.blocks_mut()[end..] {
                                 self.key.create_trivial_assign(block, 0);
                             }
                             carry_ct.blocks_mut().rotate_right(1);
@@ -0,0 +1,273 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Returns the maximum of `values` along with its encrypted index.
+    ///
+    /// The maximum is found by a tree reduction: at each level, the elements are compared by
+    /// pairs and the greatest one of each pair, along with its index, is kept. When several
+    /// elements are equal to the maximum, the index of the first one is returned.
+    ///
+    /// The index has just enough blocks to hold `values.len() - 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msgs = [12u8, 200u8, 37u8];
+    /// let cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    ///
+    /// let (ct_max, ct_index) = sks.argmax_parallelized(&cts);
+    /// let max: u8 = cks.decrypt(&ct_max);
+    /// let index: u8 = cks.decrypt(&ct_index);
+    /// assert_eq!((max, index), (200, 1));
+    /// ```
+    pub fn argmax_parallelized<T>(&self, values: &[T]) -> (T, RadixCiphertext)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.arg_reduce_parallelized(values, |lhs, rhs| self.gt_parallelized(rhs, lhs))
+    }
+
+    /// Returns the minimum of `values` along with its encrypted index.
+    ///
+    /// See [Self::argmax_parallelized].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msgs = [-12i8, 100i8, -37i8];
+    /// let cts = msgs
+    ///     .iter()
+    ///     .map(|&msg| cks.encrypt_signed(msg))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let (ct_min, ct_index) = sks.argmin_parallelized(&cts);
+    /// let min: i8 = cks.decrypt_signed(&ct_min);
+    /// let index: u8 = cks.decrypt(&ct_index);
+    /// assert_eq!((min, index), (-37, 2));
+    /// ```
+    pub fn argmin_parallelized<T>(&self, values: &[T]) -> (T, RadixCiphertext)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.arg_reduce_parallelized(values, |lhs, rhs| self.lt_parallelized(rhs, lhs))
+    }
+
+    /// Returns the `k` greatest elements of `values`, sorted in decreasing order.
+    ///
+    /// This uses a partial bitonic sorting network: the values are split in chunks of
+    /// `k.next_power_of_two()` elements which are sorted, then the chunks are merged by pairs,
+    /// only keeping the greatest half of each merge, until one chunk remains.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the number of values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msgs = [12u8, 200u8, 37u8, 150u8];
+    /// let cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    ///
+    /// let cts_res = sks.top_k_parallelized(&cts, 2);
+    /// let res = cts_res
+    ///     .iter()
+    ///     .map(|ct| cks.decrypt(ct))
+    ///     .collect::<Vec<u8>>();
+    /// assert_eq!(res, [200, 150]);
+    /// ```
+    pub fn top_k_parallelized<T>(&self, values: &[T], k: usize) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(
+            k <= values.len(),
+            "Cannot select {k} elements out of {}",
+            values.len()
+        );
+        if k == 0 {
+            return vec![];
+        }
+
+        // The last chunk is padded with the minimum value, which never makes it into the
+        // result unless it is equal to one of the k greatest values
+        let chunk_len = k.next_power_of_two();
+        let num_blocks = values[0].blocks().len();
+        let padding_len = (chunk_len - values.len() % chunk_len) % chunk_len;
+        let mut padded = values.to_vec();
+        padded.extend(
+            std::iter::repeat_with(|| self.create_trivial_min_radix(num_blocks)).take(padding_len),
+        );
+
+        let mut chunks = padded
+            .par_chunks_mut(chunk_len)
+            .map(|chunk| {
+                self.bitonic_sort_parallelized(chunk);
+                chunk.to_vec()
+            })
+            .collect::<Vec<_>>();
+
+        while chunks.len() > 1 {
+            chunks = chunks
+                .par_chunks(2)
+                .map(|pair| match pair {
+                    [lhs, rhs] => {
+                        // The pairwise maximums of a decreasing and an increasing sequence form
+                        // a bitonic sequence holding the greatest half of both
+                        let mut merged = lhs
+                            .par_iter()
+                            .zip(rhs.par_iter().rev())
+                            .map(|(lhs, rhs)| self.max_parallelized(lhs, rhs))
+                            .collect::<Vec<_>>();
+                        self.bitonic_merge_parallelized(&mut merged);
+                        merged
+                    }
+                    [chunk] => chunk.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        let mut result = chunks.pop().unwrap();
+        result.truncate(k);
+        result
+    }
+
+    /// Reduces `values` by pairs, keeping of each pair the right hand side element if
+    /// `keep_rhs(lhs, rhs)` is true, and the left hand side one otherwise.
+    fn arg_reduce_parallelized<T, F>(&self, values: &[T], keep_rhs: F) -> (T, RadixCiphertext)
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(&T, &T) -> BooleanBlock + Sync,
+    {
+        assert!(!values.is_empty(), "Cannot reduce an empty slice");
+
+        let bits_per_block = self.key.message_modulus.0.ilog2();
+        let num_index_bits = usize::BITS - (values.len() - 1).leading_zeros();
+        let num_index_blocks =
+            ((num_index_bits + bits_per_block - 1) / bits_per_block).max(1) as usize;
+
+        let mut candidates = values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let index: RadixCiphertext = self.create_trivial_radix(i as u64, num_index_blocks);
+                (value.clone(), index)
+            })
+            .collect::<Vec<_>>();
+
+        // As the pairs keep the order of the values, keeping the left hand side element on
+        // ties selects the first one
+        while candidates.len() > 1 {
+            candidates = candidates
+                .par_chunks(2)
+                .map(|pair| match pair {
+                    [(lhs, lhs_index), (rhs, rhs_index)] => {
+                        let condition = keep_rhs(lhs, rhs);
+                        rayon::join(
+                            || self.if_then_else_parallelized(&condition, rhs, lhs),
+                            || self.if_then_else_parallelized(&condition, rhs_index, lhs_index),
+                        )
+                    }
+                    [candidate] => candidate.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        candidates.pop().unwrap()
+    }
+
+    /// Sorts `values` in decreasing order, its length must be a power of two.
+    fn bitonic_sort_parallelized<T>(&self, values: &mut [T])
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let len = values.len();
+        let mut size = 2;
+        while size <= len {
+            let mut stride = size / 2;
+            while stride > 0 {
+                // Each block of `size` elements is sorted in decreasing order if its position is
+                // even, and increasing order otherwise, so that the next size is bitonic
+                self.compare_exchange_step_parallelized(values, stride, |i| i & size == 0);
+                stride /= 2;
+            }
+            size *= 2;
+        }
+    }
+
+    /// Sorts the bitonic sequence `values` in decreasing order, its length must be a power of
+    /// two.
+    fn bitonic_merge_parallelized<T>(&self, values: &mut [T])
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut stride = values.len() / 2;
+        while stride > 0 {
+            self.compare_exchange_step_parallelized(values, stride, |_| true);
+            stride /= 2;
+        }
+    }
+
+    /// Compares the elements `stride` apart of each block of `2 * stride` elements of
+    /// `values`, and swaps them so that the greatest comes first if `is_decreasing(i)`, `i`
+    /// being the index of the first one, and last otherwise.
+    fn compare_exchange_step_parallelized<T, F>(
+        &self,
+        values: &mut [T],
+        stride: usize,
+        is_decreasing: F,
+    ) where
+        T: IntegerRadixCiphertext,
+        F: Fn(usize) -> bool + Sync,
+    {
+        values
+            .par_chunks_mut(2 * stride)
+            .enumerate()
+            .for_each(|(chunk_index, chunk)| {
+                let (firsts, lasts) = chunk.split_at_mut(stride);
+                firsts
+                    .par_iter_mut()
+                    .zip(lasts.par_iter_mut())
+                    .enumerate()
+                    .for_each(|(j, (first, last))| {
+                        let i = chunk_index * 2 * stride + j;
+                        let should_swap = if is_decreasing(i) {
+                            self.lt_parallelized(first, last)
+                        } else {
+                            self.gt_parallelized(first, last)
+                        };
+                        let (new_first, new_last) = rayon::join(
+                            || self.if_then_else_parallelized(&should_swap, last, first),
+                            || self.if_then_else_parallelized(&should_swap, first, last),
+                        );
+                        *first = new_first;
+                        *last = new_last;
+                    });
+            });
+    }
+}
@@ -0,0 +1,143 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Returns the element of `values` at the encrypted `index`.
+    ///
+    /// The element is selected obliviously: `index` is compared to every position to build a
+    /// one-hot mask, every element not selected by the mask is zeroed, and the elements are then
+    /// summed. If `index` is out of bounds, the result is an encryption of zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty, or if its elements do not all have the same number of
+    /// blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msgs = [12u8, 200u8, 37u8];
+    /// let cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    /// let index = cks.encrypt(1u8);
+    ///
+    /// let ct_res = sks.select_by_index_parallelized(&cts, &index);
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msgs[1]);
+    /// ```
+    pub fn select_by_index_parallelized<T>(&self, values: &[T], index: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(!values.is_empty(), "Cannot select in an empty slice");
+        let num_blocks = values[0].blocks().len();
+        assert!(
+            values
+                .iter()
+                .all(|value| value.blocks().len() == num_blocks),
+            "All values must have the same number of blocks"
+        );
+
+        let one_hot = self.one_hot_index_parallelized(index, values.len());
+
+        let selected = values
+            .par_iter()
+            .zip(one_hot.par_iter())
+            .map(|(value, is_selected)| {
+                let mut value = value.clone();
+                if !value.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut value);
+                }
+                self.zero_out_if_condition_is_false(&mut value, is_selected.as_ref());
+                value
+            })
+            .collect::<Vec<_>>();
+
+        // As at most one value is not zeroed, the sum is that value
+        self.sum_ciphertexts_parallelized(&selected).unwrap()
+    }
+
+    /// Writes `value` in `values` at the encrypted `index`.
+    ///
+    /// As the index is encrypted, every element is updated: each one is replaced by `value` if
+    /// its position is `index`, and re-encrypts its own value otherwise. If `index` is out of
+    /// bounds, no element is changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements of `values` do not all have the same number of blocks as `value`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let mut msgs = [12u8, 200u8, 37u8];
+    /// let mut cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    /// let index = cks.encrypt(2u8);
+    /// let value = cks.encrypt(99u8);
+    ///
+    /// sks.write_at_index_parallelized(&mut cts, &index, &value);
+    /// msgs[2] = 99;
+    ///
+    /// let res = cts.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u8>>();
+    /// assert_eq!(res, msgs);
+    /// ```
+    pub fn write_at_index_parallelized<T>(
+        &self,
+        values: &mut [T],
+        index: &RadixCiphertext,
+        value: &T,
+    ) where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = value.blocks().len();
+        assert!(
+            values
+                .iter()
+                .all(|value| value.blocks().len() == num_blocks),
+            "All values must have the same number of blocks"
+        );
+
+        let one_hot = self.one_hot_index_parallelized(index, values.len());
+
+        values
+            .par_iter_mut()
+            .zip(one_hot.par_iter())
+            .for_each(|(current, is_selected)| {
+                *current = self.if_then_else_parallelized(is_selected, value, current);
+            });
+    }
+
+    /// Returns the encrypted booleans `index == i` for `i` in `0..len`, at most one of them
+    /// encrypting `true`.
+    pub(crate) fn one_hot_index_parallelized(
+        &self,
+        index: &RadixCiphertext,
+        len: usize,
+    ) -> Vec<BooleanBlock> {
+        let mut cleaned;
+        let index = if index.block_carries_are_empty() {
+            index
+        } else {
+            cleaned = index.clone();
+            self.full_propagate_parallelized(&mut cleaned);
+            &cleaned
+        };
+
+        (0..len)
+            .into_par_iter()
+            .map(|i| self.scalar_eq_parallelized(index, i as u64))
+            .collect()
+    }
+}
@@ -0,0 +1,304 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Returns the number of blocks needed to encrypt a count of bits of `ct`, that is a value
+    /// in `0..=num_bits`.
+    fn num_blocks_for_bit_count<T>(&self, ct: &T) -> usize
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let bits_per_block = self.key.message_modulus.0.ilog2();
+        let num_bits = bits_per_block * ct.blocks().len() as u32;
+        let num_bits_in_count = (u32::BITS - num_bits.leading_zeros()).max(1);
+        ((num_bits_in_count + bits_per_block - 1) / bits_per_block) as usize
+    }
+
+    /// Counts the bits of `ct` for which `bit_is_counted` is true, by summing the counts of
+    /// each block.
+    ///
+    /// ct must not have any carries
+    fn unchecked_count_bits_parallelized<T, F>(&self, ct: &T, bit_is_counted: F) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(u64) -> bool + Sync,
+    {
+        let bits_per_block = self.key.message_modulus.0.ilog2();
+        let num_blocks = self.num_blocks_for_bit_count(ct);
+
+        // A single lookup table counts all the bits of a block, where extracting each bit with
+        // the BitExtractor would take bits_per_block PBS per block and more terms to sum.
+        // A block holds at most bits_per_block bits, which is below the message modulus
+        let lut = self.key.generate_lookup_table(|x| {
+            (0..bits_per_block)
+                .filter(|i| bit_is_counted((x >> i) & 1))
+                .count() as u64
+        });
+
+        let counts = ct
+            .blocks()
+            .par_iter()
+            .map(|block| {
+                let mut blocks = vec![self.key.apply_lookup_table(block, &lut)];
+                blocks.resize_with(num_blocks, || self.key.create_trivial(0));
+                RadixCiphertext::from(blocks)
+            })
+            .collect::<Vec<_>>();
+
+        self.sum_ciphertexts_parallelized(&counts)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks))
+    }
+
+    /// Returns the number of ones in the binary representation of `ct`.
+    ///
+    /// The result is an unsigned radix with just enough blocks to encrypt the number of bits of
+    /// `ct`.
+    ///
+    /// ct must not have any carries
+    pub fn unchecked_count_ones_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.unchecked_count_bits_parallelized(ct, |bit| bit == 1)
+    }
+
+    /// Returns the number of zeros in the binary representation of `ct`.
+    ///
+    /// See [Self::unchecked_count_ones_parallelized].
+    pub fn unchecked_count_zeros_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.unchecked_count_bits_parallelized(ct, |bit| bit == 0)
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of `ct`.
+    ///
+    /// See [Self::unchecked_count_ones_parallelized].
+    pub fn unchecked_trailing_zeros_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        // The trailing zeros of x are the ones of !x & (x - 1), which is all ones when x == 0
+        let (not_ct, ct_minus_one) = rayon::join(
+            || self.bitnot_parallelized(ct),
+            || self.scalar_sub_parallelized(ct, 1u8),
+        );
+        let trailing_ones = self.bitand_parallelized(&not_ct, &ct_minus_one);
+        self.unchecked_count_ones_parallelized(&trailing_ones)
+    }
+
+    /// Returns the number of leading zeros in the binary representation of `ct`.
+    ///
+    /// See [Self::unchecked_count_ones_parallelized].
+    pub fn unchecked_leading_zeros_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        // The leading zeros are the trailing zeros of the bit-reversed ciphertext
+        let bits_per_block = self.key.message_modulus.0.ilog2();
+        let lut = self.key.generate_lookup_table(|x| {
+            (0..bits_per_block).fold(0, |reversed, i| {
+                reversed | (((x >> i) & 1) << (bits_per_block - 1 - i))
+            })
+        });
+
+        let reversed_blocks = ct
+            .blocks()
+            .par_iter()
+            .rev()
+            .map(|block| self.key.apply_lookup_table(block, &lut))
+            .collect::<Vec<_>>();
+        let reversed = RadixCiphertext::from(reversed_blocks);
+
+        self.unchecked_trailing_zeros_parallelized(&reversed)
+    }
+
+    /// Returns the base 2 logarithm of `ct`, rounded down.
+    ///
+    /// The result is meaningless if `ct` is not strictly positive,
+    /// see [Self::unchecked_checked_ilog2_parallelized].
+    ///
+    /// ct must not have any carries
+    pub fn unchecked_ilog2_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_bits = self.key.message_modulus.0.ilog2() * ct.blocks().len() as u32;
+
+        // ilog2(x) = num_bits - 1 - leading_zeros(x), computed as !leading_zeros + num_bits,
+        // as !y = -y - 1
+        let leading_zeros = self.unchecked_leading_zeros_parallelized(ct);
+        let not_leading_zeros = self.bitnot_parallelized(&leading_zeros);
+        self.scalar_add_parallelized(&not_leading_zeros, num_bits)
+    }
+
+    /// Returns the base 2 logarithm of `ct` rounded down, and whether `ct` is strictly positive
+    /// (in which case the logarithm is meaningful).
+    ///
+    /// ct must not have any carries
+    pub fn unchecked_checked_ilog2_parallelized<T>(&self, ct: &T) -> (RadixCiphertext, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        rayon::join(
+            || self.unchecked_ilog2_parallelized(ct),
+            || self.scalar_gt_parallelized(ct, 0),
+        )
+    }
+
+    /// Returns the number of ones in the binary representation of `ct`.
+    ///
+    /// The result is an unsigned radix with just enough blocks to encrypt the number of bits of
+    /// `ct`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0b1011_0100u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.count_ones_parallelized(&ct);
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, u64::from(msg.count_ones()));
+    /// ```
+    pub fn count_ones_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.with_clean_carries(ct, |ct| self.unchecked_count_ones_parallelized(ct))
+    }
+
+    /// Returns the number of zeros in the binary representation of `ct`.
+    ///
+    /// See [Self::count_ones_parallelized].
+    pub fn count_zeros_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.with_clean_carries(ct, |ct| self.unchecked_count_zeros_parallelized(ct))
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of `ct`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0b1011_0100u8;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.trailing_zeros_parallelized(&ct);
+    /// let res: u32 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg.trailing_zeros());
+    /// ```
+    pub fn trailing_zeros_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.with_clean_carries(ct, |ct| self.unchecked_trailing_zeros_parallelized(ct))
+    }
+
+    /// Returns the number of leading zeros in the binary representation of `ct`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0b0001_0100u8;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.leading_zeros_parallelized(&ct);
+    /// let res: u32 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg.leading_zeros());
+    /// ```
+    pub fn leading_zeros_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.with_clean_carries(ct, |ct| self.unchecked_leading_zeros_parallelized(ct))
+    }
+
+    /// Returns the base 2 logarithm of `ct`, rounded down.
+    ///
+    /// The result is meaningless if `ct` is not strictly positive,
+    /// see [Self::checked_ilog2_parallelized].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 100u8;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.ilog2_parallelized(&ct);
+    /// let res: u32 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg.ilog2());
+    /// ```
+    pub fn ilog2_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.with_clean_carries(ct, |ct| self.unchecked_ilog2_parallelized(ct))
+    }
+
+    /// Returns the base 2 logarithm of `ct` rounded down, and whether `ct` is strictly positive
+    /// (in which case the logarithm is meaningful).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ct = cks.encrypt(0u8);
+    ///
+    /// let (_, is_valid) = sks.checked_ilog2_parallelized(&ct);
+    /// assert!(!cks.decrypt_bool(&is_valid));
+    /// ```
+    pub fn checked_ilog2_parallelized<T>(&self, ct: &T) -> (RadixCiphertext, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.with_clean_carries(ct, |ct| self.unchecked_checked_ilog2_parallelized(ct))
+    }
+
+    fn with_clean_carries<T, R, F>(&self, ct: &T, func: F) -> R
+    where
+        T: IntegerRadixCiphertext,
+        F: FnOnce(&T) -> R,
+    {
+        if ct.block_carries_are_empty() {
+            func(ct)
+        } else {
+            let mut cloned = ct.clone();
+            self.full_propagate_parallelized(&mut cloned);
+            func(&cloned)
+        }
+    }
+}
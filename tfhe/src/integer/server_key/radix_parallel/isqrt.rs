@@ -0,0 +1,97 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{RadixCiphertext, ServerKey};
+
+impl ServerKey {
+    /// Computes homomorphically the square root of `ct`, rounded down.
+    ///
+    /// The bits of `ct` are read as an unsigned integer, so the result is meaningless if a
+    /// signed `ct` is negative.
+    ///
+    /// ct must not have any carries
+    pub fn unchecked_isqrt_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = ct.blocks().len();
+        let num_bits = self.key.message_modulus.0.ilog2() * num_blocks as u32;
+        let value = RadixCiphertext::from(ct.blocks().to_vec());
+
+        // Digit-by-digit method: the bits of the input are consumed two at a time from the most
+        // significant ones, the root is built one bit at a time and the remainder is the
+        // difference between the consumed input and the square of the root
+        let mut root: RadixCiphertext = self.create_trivial_zero_radix(num_blocks);
+        let mut remainder: RadixCiphertext = self.create_trivial_zero_radix(num_blocks);
+        for i in (0..(num_bits + 1) / 2).rev() {
+            let (next_bits, (shifted_remainder, shifted_root)) = rayon::join(
+                || {
+                    let shifted = self.scalar_right_shift_parallelized(&value, 2 * i);
+                    self.scalar_bitand_parallelized(&shifted, 3u8)
+                },
+                || {
+                    rayon::join(
+                        || self.scalar_left_shift_parallelized(&remainder, 2u32),
+                        || self.scalar_left_shift_parallelized(&root, 1u32),
+                    )
+                },
+            );
+            // The two lowest bits of the shifted remainder are zero, and so is the lowest bit
+            // of the shifted root, the additions below do not carry
+            remainder = self.add_parallelized(&shifted_remainder, &next_bits);
+            root = shifted_root;
+
+            // The root gets a new one bit if (root + 1)^2 is not greater than the consumed
+            // input, that is if the remainder is at least 2 * root + 1
+            let trial = self
+                .scalar_add_parallelized(&self.scalar_left_shift_parallelized(&root, 1u32), 1u8);
+            let (fits, difference) = rayon::join(
+                || self.ge_parallelized(&remainder, &trial),
+                || self.sub_parallelized(&remainder, &trial),
+            );
+            let (new_remainder, new_root) = rayon::join(
+                || self.if_then_else_parallelized(&fits, &difference, &remainder),
+                || {
+                    let root_with_one = self.scalar_add_parallelized(&root, 1u8);
+                    self.if_then_else_parallelized(&fits, &root_with_one, &root)
+                },
+            );
+            remainder = new_remainder;
+            root = new_root;
+        }
+
+        T::from_blocks(root.blocks)
+    }
+
+    /// Computes homomorphically the square root of `ct`, rounded down.
+    ///
+    /// The bits of `ct` are read as an unsigned integer, so the result is meaningless if a
+    /// signed `ct` is negative.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 200u8;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.isqrt_parallelized(&ct);
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 14);
+    /// ```
+    pub fn isqrt_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if ct.block_carries_are_empty() {
+            self.unchecked_isqrt_parallelized(ct)
+        } else {
+            let mut cleaned = ct.clone();
+            self.full_propagate_parallelized(&mut cleaned);
+            self.unchecked_isqrt_parallelized(&cleaned)
+        }
+    }
+}
@@ -1,13 +1,20 @@
 mod abs;
 mod add;
+mod arg_min_max;
+mod array;
 mod bit_extractor;
 mod bitwise_op;
 mod cmux;
 mod comparison;
 mod div_mod;
+mod ilog2;
+mod isqrt;
+mod modular;
 mod mul;
 mod neg;
+mod pow;
 mod rotate;
+mod saturating;
 mod scalar_add;
 mod scalar_bitwise_op;
 mod scalar_comparison;
@@ -0,0 +1,215 @@
+use crate::core_crypto::prelude::UnsignedNumeric;
+use crate::integer::block_decomposition::{BlockDecomposer, BlockRecomposer, DecomposableInto};
+use crate::integer::server_key::radix::scalar_sub::TwosComplementNegation;
+use crate::integer::{RadixCiphertext, ServerKey, U256};
+
+impl ServerKey {
+    /// Computes homomorphically `(lhs + rhs) mod modulus`.
+    ///
+    /// lhs and rhs must encrypt values below `modulus`, which must be encryptable by the
+    /// ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let modulus = 251u8;
+    /// let (msg1, msg2) = (200u8, 100u8);
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.add_mod_parallelized(&ct1, &ct2, modulus);
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, (200 + 100) % 251);
+    /// ```
+    pub fn add_mod_parallelized<Scalar>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: Scalar,
+    ) -> RadixCiphertext
+    where
+        Scalar:
+            UnsignedNumeric + DecomposableInto<u8> + DecomposableInto<u64> + TwosComplementNegation,
+    {
+        // When the sum overflows, the wrapped sum minus the modulus is still the right result,
+        // as the sum is below 2 * modulus
+        let (sum, overflowed) = self.unsigned_overflowing_add_parallelized(lhs, rhs);
+        let (is_ge, reduced) = rayon::join(
+            || self.scalar_ge_parallelized(&sum, modulus),
+            || self.scalar_sub_parallelized(&sum, modulus),
+        );
+        let must_reduce = self.boolean_bitor(&overflowed, &is_ge);
+        self.if_then_else_parallelized(&must_reduce, &reduced, &sum)
+    }
+
+    /// Computes homomorphically `(lhs - rhs) mod modulus`.
+    ///
+    /// lhs and rhs must encrypt values below `modulus`, which must be encryptable by the
+    /// ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let modulus = 251u8;
+    /// let (msg1, msg2) = (100u8, 200u8);
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.sub_mod_parallelized(&ct1, &ct2, modulus);
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 251 + 100 - 200);
+    /// ```
+    pub fn sub_mod_parallelized<Scalar>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: Scalar,
+    ) -> RadixCiphertext
+    where
+        Scalar: UnsignedNumeric + DecomposableInto<u8>,
+    {
+        // When the difference underflows, adding the modulus to the wrapped difference wraps
+        // back to the right result
+        let (difference, underflowed) = self.unsigned_overflowing_sub_parallelized(lhs, rhs);
+        let corrected = self.scalar_add_parallelized(&difference, modulus);
+        self.if_then_else_parallelized(&underflowed, &corrected, &difference)
+    }
+
+    /// Computes homomorphically `(lhs * rhs) mod modulus`.
+    ///
+    /// lhs and rhs must encrypt values below `modulus`, which must be encryptable by the
+    /// ciphertexts.
+    ///
+    /// The full product is computed on twice as many blocks, and reduced with the scalar
+    /// division, which replaces the division by a multiplication by a precomputed reciprocal
+    /// of the modulus. The reciprocal is computed on 256 bits, so ciphertexts wider than 128
+    /// bits fall back to the (slower) division by a trivially encrypted modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let modulus = 251u8;
+    /// let (msg1, msg2) = (200u8, 100u8);
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.mul_mod_parallelized(&ct1, &ct2, modulus);
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, (200 * 100) % 251);
+    /// ```
+    pub fn mul_mod_parallelized<Scalar>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: Scalar,
+    ) -> RadixCiphertext
+    where
+        Scalar: UnsignedNumeric + DecomposableInto<u64>,
+    {
+        let num_blocks = lhs.blocks.len();
+        let (lhs, rhs) = rayon::join(
+            || self.extend_radix_with_trivial_zero_blocks_msb(lhs, num_blocks),
+            || self.extend_radix_with_trivial_zero_blocks_msb(rhs, num_blocks),
+        );
+        let product = self.mul_parallelized(&lhs, &rhs);
+
+        // The scalar division needs a divisor type that holds as many bits as the product,
+        // so the modulus is widened instead of relying on the caller's Scalar type
+        let bits_in_block = self.key.message_modulus.0.ilog2();
+        let product_bits = bits_in_block as usize * product.blocks.len();
+        let remainder = if product_bits <= U256::BITS as usize {
+            let mut recomposer = BlockRecomposer::<U256>::new(bits_in_block);
+            for block in
+                BlockDecomposer::with_early_stop_at_zero(modulus, bits_in_block).iter_as::<u64>()
+            {
+                recomposer.add_unmasked(block);
+            }
+            let modulus = recomposer.value();
+            self.scalar_rem_parallelized(&product, modulus)
+        } else {
+            let modulus: RadixCiphertext = self.create_trivial_radix(modulus, product.blocks.len());
+            self.rem_parallelized(&product, &modulus)
+        };
+        self.trim_radix_blocks_msb(&remainder, num_blocks)
+    }
+
+    /// Computes homomorphically `base` raised to the power of the encrypted `exponent`, modulo
+    /// `modulus`.
+    ///
+    /// base must encrypt a value below `modulus`, which must be encryptable by the ciphertexts.
+    /// The powers are computed by square-and-multiply with [Self::mul_mod_parallelized].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let modulus = 251u8;
+    /// let (base, exponent) = (7u8, 10u8);
+    /// let ct_base = cks.encrypt(base);
+    /// let ct_exponent = cks.encrypt(exponent);
+    ///
+    /// let ct_res = sks.pow_mod_parallelized(&ct_base, &ct_exponent, modulus);
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 7u64.pow(10) % 251);
+    /// ```
+    pub fn pow_mod_parallelized<Scalar>(
+        &self,
+        base: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+        modulus: Scalar,
+    ) -> RadixCiphertext
+    where
+        Scalar: UnsignedNumeric + DecomposableInto<u64>,
+    {
+        let num_blocks = base.blocks.len();
+        let one = u64::from(modulus != Scalar::ONE);
+
+        let mut cleaned;
+        let base = if base.block_carries_are_empty() {
+            base
+        } else {
+            cleaned = base.clone();
+            self.full_propagate_parallelized(&mut cleaned);
+            &cleaned
+        };
+
+        let mut cleaned;
+        let exponent = if exponent.block_carries_are_empty() {
+            exponent
+        } else {
+            cleaned = exponent.clone();
+            self.full_propagate_parallelized(&mut cleaned);
+            &cleaned
+        };
+
+        self.unchecked_pow_with_parallelized(
+            base,
+            exponent,
+            self.create_trivial_radix(one, num_blocks),
+            |lhs, rhs| self.mul_mod_parallelized(lhs, rhs, modulus),
+        )
+    }
+}
@@ -1,5 +1,5 @@
-use crate::integer::ciphertext::IntegerRadixCiphertext;
-use crate::integer::ServerKey;
+use crate::integer::ciphertext::{IntegerCiphertext, IntegerRadixCiphertext};
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
 use rayon::prelude::*;
 
 impl ServerKey {
@@ -588,4 +588,256 @@ impl ServerKey {
 
         self.unchecked_mul_assign_parallelized(lhs, rhs);
     }
+
+    /// Computes the multiplication of two unsigned ciphertexts and returns the overflow flag
+    ///
+    /// The full product is computed on twice as many blocks: the result is its lower half, and
+    /// the multiplication overflowed if its upper half is not zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 170u8;
+    /// let msg2 = 6u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let (ct_res, overflowed) = sks.unsigned_overflowing_mul_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// let dec_overflowed = cks.decrypt_bool(&overflowed);
+    /// let (expected_result, expected_overflow) = msg1.overflowing_mul(msg2);
+    /// assert_eq!(dec_result, expected_result);
+    /// assert_eq!(dec_overflowed, expected_overflow);
+    /// ```
+    pub fn unsigned_overflowing_mul_parallelized(
+        &self,
+        ct_left: &RadixCiphertext,
+        ct_right: &RadixCiphertext,
+    ) -> (RadixCiphertext, BooleanBlock) {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (
+            ct_left.block_carries_are_empty(),
+            ct_right.block_carries_are_empty(),
+        ) {
+            (true, true) => (ct_left, ct_right),
+            (true, false) => {
+                tmp_rhs = ct_right.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (ct_left, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = ct_left.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, ct_right)
+            }
+            (false, false) => {
+                tmp_lhs = ct_left.clone();
+                tmp_rhs = ct_right.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_unsigned_overflowing_mul_parallelized(lhs, rhs)
+    }
+
+    /// Computes the multiplication of two unsigned ciphertexts and returns the overflow flag
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// ciphertext: the inputs must have empty carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 170u8;
+    /// let msg2 = 6u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let (ct_res, overflowed) = sks.unchecked_unsigned_overflowing_mul_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// let dec_overflowed = cks.decrypt_bool(&overflowed);
+    /// let (expected_result, expected_overflow) = msg1.overflowing_mul(msg2);
+    /// assert_eq!(dec_result, expected_result);
+    /// assert_eq!(dec_overflowed, expected_overflow);
+    /// ```
+    pub fn unchecked_unsigned_overflowing_mul_parallelized(
+        &self,
+        ct_left: &RadixCiphertext,
+        ct_right: &RadixCiphertext,
+    ) -> (RadixCiphertext, BooleanBlock) {
+        let num_blocks = ct_left.blocks.len();
+        let (lhs, rhs) = rayon::join(
+            || self.extend_radix_with_trivial_zero_blocks_msb(ct_left, num_blocks),
+            || self.extend_radix_with_trivial_zero_blocks_msb(ct_right, num_blocks),
+        );
+        let mut product = self.mul_parallelized(&lhs, &rhs);
+
+        let high_half = RadixCiphertext::from(product.blocks.split_off(num_blocks));
+        let overflowed = self.scalar_ne_parallelized(&high_half, 0);
+        (product, overflowed)
+    }
+
+    /// Computes the multiplication of two signed ciphertexts and returns the overflow flag
+    ///
+    /// The full product is computed on twice as many blocks: the result is its lower half, and
+    /// the multiplication overflowed if the full product is not the sign extension of the
+    /// result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -100i8;
+    /// let msg2 = 2i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let (ct_res, overflowed) = sks.signed_overflowing_mul_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// let dec_overflowed = cks.decrypt_bool(&overflowed);
+    /// let (expected_result, expected_overflow) = msg1.overflowing_mul(msg2);
+    /// assert_eq!(dec_result, expected_result);
+    /// assert_eq!(dec_overflowed, expected_overflow);
+    /// ```
+    pub fn signed_overflowing_mul_parallelized(
+        &self,
+        ct_left: &SignedRadixCiphertext,
+        ct_right: &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, BooleanBlock) {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (
+            ct_left.block_carries_are_empty(),
+            ct_right.block_carries_are_empty(),
+        ) {
+            (true, true) => (ct_left, ct_right),
+            (true, false) => {
+                tmp_rhs = ct_right.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (ct_left, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = ct_left.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, ct_right)
+            }
+            (false, false) => {
+                tmp_lhs = ct_left.clone();
+                tmp_rhs = ct_right.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_signed_overflowing_mul_parallelized(lhs, rhs)
+    }
+
+    /// Computes the multiplication of two signed ciphertexts and returns the overflow flag
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// ciphertext: the inputs must have empty carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -100i8;
+    /// let msg2 = 2i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let (ct_res, overflowed) = sks.unchecked_signed_overflowing_mul_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// let dec_overflowed = cks.decrypt_bool(&overflowed);
+    /// let (expected_result, expected_overflow) = msg1.overflowing_mul(msg2);
+    /// assert_eq!(dec_result, expected_result);
+    /// assert_eq!(dec_overflowed, expected_overflow);
+    /// ```
+    pub fn unchecked_signed_overflowing_mul_parallelized(
+        &self,
+        ct_left: &SignedRadixCiphertext,
+        ct_right: &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, BooleanBlock) {
+        let num_blocks = ct_left.blocks.len();
+        let (lhs, rhs) = rayon::join(
+            || self.extend_radix_with_sign_msb(ct_left, num_blocks),
+            || self.extend_radix_with_sign_msb(ct_right, num_blocks),
+        );
+        // The product of two values of n bits fits in 2n bits, so it does not wrap
+        let product = self.mul_parallelized(&lhs, &rhs);
+
+        let result = SignedRadixCiphertext::from(product.blocks[..num_blocks].to_vec());
+        let extended_result = self.extend_radix_with_sign_msb(&result, num_blocks);
+        let overflowed = self.ne_parallelized(&product, &extended_result);
+        (result, overflowed)
+    }
+
+    /// Computes the multiplication of two ciphertexts and returns the overflow flag
+    ///
+    /// See [Self::unsigned_overflowing_mul_parallelized] and
+    /// [Self::signed_overflowing_mul_parallelized].
+    pub fn overflowing_mul_parallelized<T>(&self, ct_left: &T, ct_right: &T) -> (T, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if T::IS_SIGNED {
+            let lhs = SignedRadixCiphertext::from_blocks(ct_left.blocks().to_vec());
+            let rhs = SignedRadixCiphertext::from_blocks(ct_right.blocks().to_vec());
+            let (result, overflowed) = self.signed_overflowing_mul_parallelized(&lhs, &rhs);
+            (T::from_blocks(result.into_blocks()), overflowed)
+        } else {
+            let lhs = RadixCiphertext::from_blocks(ct_left.blocks().to_vec());
+            let rhs = RadixCiphertext::from_blocks(ct_right.blocks().to_vec());
+            let (result, overflowed) = self.unsigned_overflowing_mul_parallelized(&lhs, &rhs);
+            (T::from_blocks(result.into_blocks()), overflowed)
+        }
+    }
 }
@@ -0,0 +1,163 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::server_key::radix_parallel::bit_extractor::BitExtractor;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+
+impl ServerKey {
+    /// Computes homomorphically `ct` raised to the power of the clear `exponent`.
+    ///
+    /// The result is wrapped around the number of bits of `ct`, like `wrapping_pow` does on
+    /// clear integers. The powers are computed by square-and-multiply, with one multiplication
+    /// per bit of `exponent` and one per bit set in it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 3u8;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_pow_parallelized(&ct, 5);
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg.wrapping_pow(5));
+    /// ```
+    pub fn scalar_pow_parallelized<T>(&self, ct: &T, exponent: u64) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = ct.blocks().len();
+        let mut result: Option<T> = None;
+        // The carries are propagated once here rather than in each multiplication, this also
+        // makes the result clean when it is the base itself (exponent 1)
+        let mut power = ct.clone();
+        if !power.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut power);
+        }
+
+        let num_bits = u64::BITS - exponent.leading_zeros();
+        for i in 0..num_bits {
+            if (exponent >> i) & 1 == 1 {
+                result = Some(result.map_or_else(
+                    || power.clone(),
+                    |result| self.mul_parallelized(&result, &power),
+                ));
+            }
+            if i + 1 < num_bits {
+                power = self.mul_parallelized(&power, &power);
+            }
+        }
+
+        result.unwrap_or_else(|| self.create_trivial_radix(1u64, num_blocks))
+    }
+
+    /// Computes homomorphically `base` raised to the power of the encrypted `exponent`.
+    ///
+    /// The result is wrapped around the number of bits of `base`, like `wrapping_pow` does on
+    /// clear integers. The powers are computed by square-and-multiply: for each bit of
+    /// `exponent`, the running product is multiplied by the current power of `base` and the
+    /// bit selects, with a cmux, whether the product is kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let base = 3u8;
+    /// let exponent = 5u8;
+    /// let ct_base = cks.encrypt(base);
+    /// let ct_exponent = cks.encrypt(exponent);
+    ///
+    /// let ct_res = sks.pow_parallelized(&ct_base, &ct_exponent);
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, base.wrapping_pow(u32::from(exponent)));
+    /// ```
+    pub fn pow_parallelized<T>(&self, base: &T, exponent: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut cleaned;
+        let base = if base.block_carries_are_empty() {
+            base
+        } else {
+            cleaned = base.clone();
+            self.full_propagate_parallelized(&mut cleaned);
+            &cleaned
+        };
+
+        let mut cleaned;
+        let exponent = if exponent.block_carries_are_empty() {
+            exponent
+        } else {
+            cleaned = exponent.clone();
+            self.full_propagate_parallelized(&mut cleaned);
+            &cleaned
+        };
+
+        self.unchecked_pow_parallelized(base, exponent)
+    }
+
+    /// Computes homomorphically `base` raised to the power of the encrypted `exponent`.
+    ///
+    /// See [Self::pow_parallelized].
+    ///
+    /// base and exponent must not have any carries
+    pub fn unchecked_pow_parallelized<T>(&self, base: &T, exponent: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = base.blocks().len();
+        self.unchecked_pow_with_parallelized(
+            base,
+            exponent,
+            self.create_trivial_radix(1u64, num_blocks),
+            |lhs, rhs| self.mul_parallelized(lhs, rhs),
+        )
+    }
+
+    /// Computes `base` raised to the power of the encrypted `exponent` by square-and-multiply,
+    /// `one` being the neutral element of the `mul` operation.
+    ///
+    /// base and exponent must not have any carries
+    pub(super) fn unchecked_pow_with_parallelized<T, F>(
+        &self,
+        base: &T,
+        exponent: &RadixCiphertext,
+        one: T,
+        mul: F,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(&T, &T) -> T + Sync,
+    {
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+
+        let exponent_bits = BitExtractor::new(self, bits_per_block)
+            .extract_all_bits(&exponent.blocks)
+            .into_iter()
+            .map(BooleanBlock::new_unchecked)
+            .collect::<Vec<_>>();
+
+        let mut result = one;
+        let mut power = base.clone();
+        for (i, bit) in exponent_bits.iter().enumerate() {
+            let (product, next_power) = rayon::join(
+                || mul(&result, &power),
+                || (i + 1 < exponent_bits.len()).then(|| mul(&power, &power)),
+            );
+            result = self.if_then_else_parallelized(bit, &product, &result);
+            if let Some(next_power) = next_power {
+                power = next_power;
+            }
+        }
+
+        result
+    }
+}
@@ -0,0 +1,140 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{BooleanBlock, ServerKey};
+
+impl ServerKey {
+    /// Computes homomorphically the addition of `lhs` and `rhs`, saturating at the bounds of the
+    /// integer type instead of wrapping around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let (msg1, msg2) = (200u8, 100u8);
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.saturating_add_parallelized(&ct1, &ct2);
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg1.saturating_add(msg2));
+    /// ```
+    pub fn saturating_add_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = lhs.blocks().len();
+        let ((result, overflowed), bound) = rayon::join(
+            || self.overflowing_add_parallelized(lhs, rhs),
+            || {
+                if T::IS_SIGNED {
+                    // A signed addition only overflows when both operands have the same sign
+                    let lhs_is_negative = self.scalar_lt_parallelized(lhs, 0);
+                    self.saturation_bound(&lhs_is_negative, num_blocks)
+                } else {
+                    self.create_trivial_max_radix(num_blocks)
+                }
+            },
+        );
+        self.if_then_else_parallelized(&overflowed, &bound, &result)
+    }
+
+    /// Computes homomorphically the subtraction of `rhs` from `lhs`, saturating at the bounds of
+    /// the integer type instead of wrapping around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let (msg1, msg2) = (-100i8, 100i8);
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.saturating_sub_parallelized(&ct1, &ct2);
+    /// let res: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(res, msg1.saturating_sub(msg2));
+    /// ```
+    pub fn saturating_sub_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = lhs.blocks().len();
+        let ((result, overflowed), bound) = rayon::join(
+            || self.overflowing_sub_parallelized(lhs, rhs),
+            || {
+                if T::IS_SIGNED {
+                    // A signed subtraction only overflows when the operands have different signs
+                    let lhs_is_negative = self.scalar_lt_parallelized(lhs, 0);
+                    self.saturation_bound(&lhs_is_negative, num_blocks)
+                } else {
+                    self.create_trivial_min_radix(num_blocks)
+                }
+            },
+        );
+        self.if_then_else_parallelized(&overflowed, &bound, &result)
+    }
+
+    /// Computes homomorphically the multiplication of `lhs` and `rhs`, saturating at the bounds
+    /// of the integer type instead of wrapping around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let (msg1, msg2) = (20u8, 30u8);
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.saturating_mul_parallelized(&ct1, &ct2);
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg1.saturating_mul(msg2));
+    /// ```
+    pub fn saturating_mul_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = lhs.blocks().len();
+        let ((result, overflowed), bound) = rayon::join(
+            || self.overflowing_mul_parallelized(lhs, rhs),
+            || {
+                if T::IS_SIGNED {
+                    // The product is negative when the operands have different signs
+                    let (lhs_is_negative, rhs_is_negative) = rayon::join(
+                        || self.scalar_lt_parallelized(lhs, 0),
+                        || self.scalar_lt_parallelized(rhs, 0),
+                    );
+                    let product_is_negative =
+                        self.boolean_bitxor(&lhs_is_negative, &rhs_is_negative);
+                    self.saturation_bound(&product_is_negative, num_blocks)
+                } else {
+                    self.create_trivial_max_radix(num_blocks)
+                }
+            },
+        );
+        self.if_then_else_parallelized(&overflowed, &bound, &result)
+    }
+
+    /// Returns the minimum value of the integer type if `is_negative` is true, and the maximum
+    /// one otherwise.
+    fn saturation_bound<T>(&self, is_negative: &BooleanBlock, num_blocks: usize) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let min = self.create_trivial_min_radix::<T>(num_blocks);
+        let max = self.create_trivial_max_radix::<T>(num_blocks);
+        self.if_then_else_parallelized(is_negative, &min, &max)
+    }
+}
@@ -1,5 +1,5 @@
 use super::add::OutputCarry;
-use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::ciphertext::{IntegerCiphertext, IntegerRadixCiphertext};
 use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
 use crate::shortint::ciphertext::Degree;
 use crate::shortint::Ciphertext;
@@ -830,4 +830,25 @@ impl ServerKey {
 
         self.unchecked_signed_overflowing_sub_parallelized(lhs, rhs)
     }
+
+    /// Computes the subtraction of two ciphertexts and returns the overflow flag
+    ///
+    /// See [Self::unsigned_overflowing_sub_parallelized] and
+    /// [Self::signed_overflowing_sub_parallelized].
+    pub fn overflowing_sub_parallelized<T>(&self, ct_left: &T, ct_right: &T) -> (T, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if T::IS_SIGNED {
+            let lhs = SignedRadixCiphertext::from_blocks(ct_left.blocks().to_vec());
+            let rhs = SignedRadixCiphertext::from_blocks(ct_right.blocks().to_vec());
+            let (result, overflowed) = self.signed_overflowing_sub_parallelized(&lhs, &rhs);
+            (T::from_blocks(result.into_blocks()), overflowed)
+        } else {
+            let lhs = RadixCiphertext::from_blocks(ct_left.blocks().to_vec());
+            let rhs = RadixCiphertext::from_blocks(ct_right.blocks().to_vec());
+            let (result, overflowed) = self.unsigned_overflowing_sub_parallelized(&lhs, &rhs);
+            (T::from_blocks(result.into_blocks()), overflowed)
+        }
+    }
 }
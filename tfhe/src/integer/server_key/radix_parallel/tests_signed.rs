@@ -326,6 +326,7 @@ fn random_non_zero_value(rng: &mut ThreadRng, modulus: i64) -> i64 {
 
 create_parametrized_test!(integer_signed_encrypt_decrypt);
 create_parametrized_test!(integer_signed_encrypt_decrypt_128_bits);
+create_parametrized_test!(integer_signed_encrypt_decrypt_into_wider_type);
 
 fn integer_signed_encrypt_decrypt_128_bits(param: impl Into<PBSParameters>) {
     let (cks, _) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
@@ -344,6 +345,25 @@ fn integer_signed_encrypt_decrypt_128_bits(param: impl Into<PBSParameters>) {
     }
 }
 
+fn integer_signed_encrypt_decrypt_into_wider_type(param: impl Into<PBSParameters>) {
+    let (cks, _) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+    let num_bits = cks.parameters().message_modulus().0.ilog2() as usize;
+    // More than 32 bits, but less than the 64 bits of the clear type, so that the decrypted
+    // value has to be sign extended from a bit above the 32nd one
+    let num_block = 48 / num_bits;
+    let modulus = 1i64 << (num_block * num_bits - 1);
+
+    for _ in 0..NB_TESTS {
+        let clear = rng.gen_range(-modulus..modulus);
+
+        let ct = cks.encrypt_signed_radix(clear, num_block);
+        let dec: i64 = cks.decrypt_signed_radix(&ct);
+        assert_eq!(clear, dec);
+    }
+}
+
 fn integer_signed_encrypt_decrypt(param: impl Into<PBSParameters>) {
     let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
 
@@ -1388,6 +1408,32 @@ create_parametrized_test!(integer_signed_default_bitand);
 create_parametrized_test!(integer_signed_default_bitor);
 create_parametrized_test!(integer_signed_default_bitxor);
 create_parametrized_test!(integer_signed_default_absolute_value);
+create_parametrized_test!(integer_signed_default_bit_counts);
+create_parametrized_test!(integer_signed_default_pow);
+create_parametrized_test!(integer_signed_default_overflowing_mul);
+create_parametrized_test!(integer_signed_default_isqrt {
+    // The remainder is compared to the trial root at each step, and comparisons need 4 bits of
+    // message and carry, so the 1_1 parameters are not supported
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS
+});
+create_parametrized_test!(integer_signed_default_saturating_ops {
+    // Unlike the unsigned saturating operations, which only use the overflow flags, the signed
+    // ones compare the operands to 0 to get their signs. Comparisons need 4 bits of message and
+    // carry, so the 1_1 parameters are not supported
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS
+});
 create_parametrized_test!(integer_signed_default_left_shift {
     // Requires 3 bits, so 1_1 parameters are not supported
     // until they get their own version of the algorithm
@@ -2033,6 +2079,161 @@ fn integer_signed_default_absolute_value(param: impl Into<PBSParameters>) {
     }
 }
 
+fn integer_signed_default_bit_counts(param: impl Into<PBSParameters>) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+    let num_bits = modulus.ilog2() + 1;
+
+    let clears = [0, 1, -1, -modulus, modulus - 1]
+        .into_iter()
+        .chain((0..NB_TESTS_SMALLER).map(|_| rng.gen::<i64>() % modulus));
+
+    for clear in clears {
+        let ctxt = cks.encrypt_signed_radix(clear, NB_CTXT);
+
+        // The bits of the ciphertext are the num_bits lowest bits of the two's complement
+        let clear_bits = (clear as u64) & ((1u64 << num_bits) - 1);
+
+        let ct_res = sks.count_ones_parallelized(&ctxt);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(dec_res, u64::from(clear_bits.count_ones()));
+
+        let ct_res = sks.count_zeros_parallelized(&ctxt);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(dec_res, u64::from(num_bits - clear_bits.count_ones()));
+
+        let ct_res = sks.leading_zeros_parallelized(&ctxt);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(
+            dec_res,
+            u64::from(clear_bits.leading_zeros() - (u64::BITS - num_bits))
+        );
+
+        let ct_res = sks.trailing_zeros_parallelized(&ctxt);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(
+            dec_res,
+            u64::from(clear_bits.trailing_zeros().min(num_bits))
+        );
+
+        let (ct_res, is_ok) = sks.checked_ilog2_parallelized(&ctxt);
+        assert_eq!(cks.decrypt_bool(&is_ok), clear > 0);
+        if let Some(clear_res) = clear.checked_ilog2() {
+            let dec_res: u64 = cks.decrypt_radix(&ct_res);
+            assert_eq!(dec_res, u64::from(clear_res));
+        }
+    }
+}
+
+fn integer_signed_default_pow(param: impl Into<PBSParameters>) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_base = rng.gen::<i64>() % modulus;
+        let clear_exponent = rng.gen::<u64>() % (2 * modulus as u64);
+
+        let ct_base = cks.encrypt_signed_radix(clear_base, NB_CTXT);
+        let ct_exponent = cks.encrypt_radix(clear_exponent, NB_CTXT);
+
+        let expected = (0..clear_exponent).fold(1, |acc, _| {
+            signed_mul_under_modulus(acc, clear_base, modulus)
+        });
+
+        let ct_res = sks.pow_parallelized(&ct_base, &ct_exponent);
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(dec_res, expected);
+
+        let ct_res = sks.scalar_pow_parallelized(&ct_base, clear_exponent);
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(dec_res, expected);
+    }
+}
+
+fn integer_signed_default_overflowing_mul(param: impl Into<PBSParameters>) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_0 = rng.gen::<i64>() % modulus;
+        let clear_1 = rng.gen::<i64>() % modulus;
+        // Add a scalar to have non clean ciphertexts
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+
+        let ctxt_0 = sks.unchecked_scalar_add(&cks.encrypt_signed_radix(clear_0, NB_CTXT), clear_2);
+        let ctxt_1 = cks.encrypt_signed_radix(clear_1, NB_CTXT);
+        let clear_0 = signed_add_under_modulus(clear_0, clear_2, modulus);
+
+        let (ct_res, overflowed) = sks.signed_overflowing_mul_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+
+        let product = clear_0 * clear_1;
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(dec_res, signed_mul_under_modulus(clear_0, clear_1, modulus));
+        assert_eq!(
+            cks.decrypt_bool(&overflowed),
+            !(-modulus..modulus).contains(&product)
+        );
+    }
+}
+
+fn integer_signed_default_saturating_ops(param: impl Into<PBSParameters>) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_0 = rng.gen::<i64>() % modulus;
+        let clear_1 = rng.gen::<i64>() % modulus;
+
+        let ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        let ctxt_1 = cks.encrypt_signed_radix(clear_1, NB_CTXT);
+
+        let ct_res = sks.saturating_add_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(dec_res, (clear_0 + clear_1).clamp(-modulus, modulus - 1));
+
+        let ct_res = sks.saturating_sub_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(dec_res, (clear_0 - clear_1).clamp(-modulus, modulus - 1));
+
+        let ct_res = sks.saturating_mul_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(dec_res, (clear_0 * clear_1).clamp(-modulus, modulus - 1));
+    }
+}
+
+fn integer_signed_default_isqrt(param: impl Into<PBSParameters>) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    let clears = [0, 1, modulus - 1]
+        .into_iter()
+        .chain((0..NB_TESTS_SMALLER).map(|_| rng.gen_range(0..modulus)));
+
+    for clear in clears {
+        let ctxt = cks.encrypt_signed_radix(clear, NB_CTXT);
+
+        let ct_res = sks.isqrt_parallelized(&ctxt);
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert!(dec_res * dec_res <= clear && (dec_res + 1) * (dec_res + 1) > clear);
+    }
+}
+
 fn integer_signed_default_left_shift<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -109,6 +109,7 @@ create_parametrized_test!(integer_default_div {
 create_parametrized_test!(integer_smart_add);
 create_parametrized_test!(integer_smart_sum_ciphertexts_slice);
 create_parametrized_test!(integer_default_sum_ciphertexts_vec);
+create_parametrized_test!(integer_default_sum_ciphertexts_vec_single_block);
 create_parametrized_test!(integer_default_add);
 create_parametrized_test!(integer_default_overflowing_add);
 create_parametrized_test!(integer_default_add_work_efficient {
@@ -227,6 +228,50 @@ create_parametrized_test!(integer_default_scalar_add);
 create_parametrized_test!(integer_default_overflowing_scalar_add);
 create_parametrized_test!(integer_smart_if_then_else);
 create_parametrized_test!(integer_default_if_then_else);
+create_parametrized_test!(integer_default_bit_counts);
+create_parametrized_test!(integer_default_pow);
+create_parametrized_test!(integer_default_overflowing_mul);
+create_parametrized_test!(integer_default_saturating_ops);
+create_parametrized_test!(integer_default_modular_ops {
+    // The reductions compare the values to the modulus, and comparisons need 4 bits of message
+    // and carry, so the 1_1 parameters are not supported
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS
+});
+create_parametrized_test!(integer_default_select_and_write_by_index {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS
+});
+create_parametrized_test!(integer_default_arg_min_max_and_top_k {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS
+});
+create_parametrized_test!(integer_default_isqrt {
+    // The remainder is compared to the trial root at each step, and comparisons need 4 bits of
+    // message and carry, so the 1_1 parameters are not supported
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS
+});
 create_parametrized_test!(integer_trim_radix_msb_blocks_handles_dirty_inputs);
 
 create_parametrized_test!(integer_unchecked_add);
@@ -633,6 +678,42 @@ where
     }
 }
 
+fn integer_default_sum_ciphertexts_vec_single_block<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, 1));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters().message_modulus().0 as u64;
+
+    // The carries of the last block are thrown away, so single block ciphertexts have no carry
+    // to propagate, even when enough of them are summed to fill the carry space
+    for len in [1, 2, 15, 16, 17] {
+        for _ in 0..NB_TESTS_SMALLER {
+            let clears = (0..len)
+                .map(|_| rng.gen::<u64>() % modulus)
+                .collect::<Vec<_>>();
+
+            // encryption of integers
+            let ctxts = clears
+                .iter()
+                .copied()
+                .map(|clear| cks.encrypt(clear))
+                .collect::<Vec<_>>();
+
+            let ct_res = sks.sum_ciphertexts_parallelized(&ctxts).unwrap();
+            let ct_res: u64 = cks.decrypt(&ct_res);
+            let clear = clears.iter().sum::<u64>() % modulus;
+
+            assert_eq!(ct_res, clear);
+        }
+    }
+}
+
 //=============================================================================
 // Smart Scalar Tests
 //=============================================================================
@@ -1032,3 +1113,323 @@ where
     let executor = CpuFunctionExecutor::new(&ServerKey::full_propagate_parallelized);
     full_propagate_test(param, executor);
 }
+
+fn integer_default_bit_counts<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+    let num_bits = modulus.ilog2();
+
+    let clears = [0, 1, modulus - 1]
+        .into_iter()
+        .chain((0..NB_TESTS_SMALLER).map(|_| rng.gen::<u64>() % modulus));
+
+    for clear in clears {
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.count_ones_parallelized(&ctxt);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, u64::from(clear.count_ones()));
+
+        let ct_res = sks.count_zeros_parallelized(&ctxt);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, u64::from(num_bits - clear.count_ones()));
+
+        let ct_res = sks.leading_zeros_parallelized(&ctxt);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            u64::from(clear.leading_zeros() - (u64::BITS - num_bits))
+        );
+
+        let ct_res = sks.trailing_zeros_parallelized(&ctxt);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, u64::from(clear.trailing_zeros().min(num_bits)));
+
+        let (ct_res, is_ok) = sks.checked_ilog2_parallelized(&ctxt);
+        assert_eq!(cks.decrypt_bool(&is_ok), clear > 0);
+        if let Some(clear_res) = clear.checked_ilog2() {
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(dec_res, u64::from(clear_res));
+        }
+    }
+}
+
+fn integer_default_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_base = rng.gen::<u64>() % modulus;
+        let clear_exponent = rng.gen::<u64>() % modulus;
+
+        let ct_base = cks.encrypt(clear_base);
+        let ct_exponent = cks.encrypt(clear_exponent);
+
+        let expected = (0..clear_exponent).fold(1, |acc, _| acc * clear_base % modulus);
+
+        let ct_res = sks.pow_parallelized(&ct_base, &ct_exponent);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, expected);
+
+        let ct_res = sks.scalar_pow_parallelized(&ct_base, clear_exponent);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, expected);
+    }
+
+    let ct_base = cks.encrypt(rng.gen::<u64>() % modulus);
+    let ct_res = sks.scalar_pow_parallelized(&ct_base, 0);
+    let dec_res: u64 = cks.decrypt(&ct_res);
+    assert_eq!(dec_res, 1);
+
+    // The result has clean carries, even when it is the base itself
+    let (clear_0, clear_1) = (rng.gen::<u64>() % modulus, rng.gen::<u64>() % modulus);
+    let ct_base = sks.unchecked_add(&cks.encrypt(clear_0), &cks.encrypt(clear_1));
+    let ct_res = sks.scalar_pow_parallelized(&ct_base, 1);
+    assert!(ct_res.block_carries_are_empty());
+    let dec_res: u64 = cks.decrypt(&ct_res);
+    assert_eq!(dec_res, (clear_0 + clear_1) % modulus);
+}
+
+fn integer_default_isqrt<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    let clears = [0, 1, modulus - 1]
+        .into_iter()
+        .chain((0..NB_TESTS_SMALLER).map(|_| rng.gen::<u64>() % modulus));
+
+    for clear in clears {
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.isqrt_parallelized(&ctxt);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert!(dec_res * dec_res <= clear && (dec_res + 1) * (dec_res + 1) > clear);
+    }
+}
+
+fn integer_default_modular_ops<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    // The largest modulus makes the additions overflow the ciphertexts
+    let clear_moduli = std::iter::once(modulus - 1)
+        .chain((0..NB_TESTS_SMALLER).map(|_| rng.gen_range(2..modulus)))
+        .collect::<Vec<_>>();
+
+    for clear_modulus in clear_moduli {
+        let clear_0 = rng.gen::<u64>() % clear_modulus;
+        let clear_1 = rng.gen::<u64>() % clear_modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.add_mod_parallelized(&ctxt_0, &ctxt_1, clear_modulus);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + clear_1) % clear_modulus);
+
+        let ct_res = sks.sub_mod_parallelized(&ctxt_0, &ctxt_1, clear_modulus);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + clear_modulus - clear_1) % clear_modulus);
+
+        let ct_res = sks.mul_mod_parallelized(&ctxt_0, &ctxt_1, clear_modulus);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0 * clear_1 % clear_modulus);
+
+        // The modulus type does not need to hold the product
+        if let Ok(small_modulus) = u8::try_from(clear_modulus) {
+            let ct_res = sks.mul_mod_parallelized(&ctxt_0, &ctxt_1, small_modulus);
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(dec_res, clear_0 * clear_1 % clear_modulus);
+        }
+
+        let ct_res = sks.pow_mod_parallelized(&ctxt_0, &ctxt_1, clear_modulus);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = (0..clear_1).fold(1 % clear_modulus, |acc, _| acc * clear_0 % clear_modulus);
+        assert_eq!(dec_res, expected);
+    }
+}
+
+fn integer_default_overflowing_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        // Add a scalar to have non clean ciphertexts
+        let clear_2 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = sks.unchecked_scalar_add(&cks.encrypt(clear_0), clear_2);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let clear_0 = (clear_0 + clear_2) % modulus;
+
+        let (ct_res, overflowed) = sks.unsigned_overflowing_mul_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+
+        let product = clear_0 * clear_1;
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, product % modulus);
+        assert_eq!(cks.decrypt_bool(&overflowed), product >= modulus);
+    }
+}
+
+fn integer_default_saturating_ops<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.saturating_add_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + clear_1).min(modulus - 1));
+
+        let ct_res = sks.saturating_sub_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0.saturating_sub(clear_1));
+
+        let ct_res = sks.saturating_mul_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 * clear_1).min(modulus - 1));
+    }
+}
+
+fn integer_default_select_and_write_by_index<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    let len = 5;
+    let mut clears = (0..len)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let mut ctxts = clears
+        .iter()
+        .map(|&clear| cks.encrypt(clear))
+        .collect::<Vec<_>>();
+
+    // The last index is out of bounds
+    for index in [0, rng.gen_range(1..len - 1), len - 1, len] {
+        let ctxt_index = cks.encrypt(index as u64);
+
+        let ct_res = sks.select_by_index_parallelized(&ctxts, &ctxt_index);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clears.get(index).copied().unwrap_or(0));
+
+        let value = rng.gen::<u64>() % modulus;
+        sks.write_at_index_parallelized(&mut ctxts, &ctxt_index, &cks.encrypt(value));
+        if let Some(clear) = clears.get_mut(index) {
+            *clear = value;
+        }
+        let dec_res = ctxts.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+        assert_eq!(dec_res, clears);
+    }
+}
+
+fn integer_default_arg_min_max_and_top_k<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    let len = 5;
+    for _ in 0..NB_TESTS_SMALLER {
+        let mut clears = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        // Duplicate an element so that ties are exercised
+        clears[len - 1] = clears[rng.gen_range(0..len - 1)];
+        let ctxts = clears
+            .iter()
+            .map(|&clear| cks.encrypt(clear))
+            .collect::<Vec<_>>();
+
+        // On ties, the index of the first occurrence is expected
+        let max_index = (0..len).fold(0, |acc, i| if clears[i] > clears[acc] { i } else { acc });
+        let min_index = (0..len).fold(0, |acc, i| if clears[i] < clears[acc] { i } else { acc });
+
+        let (ct_max, ct_max_index) = sks.argmax_parallelized(&ctxts);
+        let dec_max: u64 = cks.decrypt(&ct_max);
+        let dec_max_index: u64 = cks.decrypt(&ct_max_index);
+        assert_eq!(
+            (dec_max, dec_max_index),
+            (clears[max_index], max_index as u64)
+        );
+
+        let (ct_min, ct_min_index) = sks.argmin_parallelized(&ctxts);
+        let dec_min: u64 = cks.decrypt(&ct_min);
+        let dec_min_index: u64 = cks.decrypt(&ct_min_index);
+        assert_eq!(
+            (dec_min, dec_min_index),
+            (clears[min_index], min_index as u64)
+        );
+
+        let k = rng.gen_range(0..=len);
+        let ct_res = sks.top_k_parallelized(&ctxts, k);
+        let dec_res = ct_res
+            .iter()
+            .map(|ct| cks.decrypt(ct))
+            .collect::<Vec<u64>>();
+        let mut expected = clears.clone();
+        expected.sort_unstable_by(|a, b| b.cmp(a));
+        expected.truncate(k);
+        assert_eq!(dec_res, expected);
+    }
+}
@@ -92,6 +92,13 @@ pub mod integer;
 /// cbindgen:ignore
 pub mod shortint;
 
+#[cfg(feature = "integer")]
+/// Welcome to the TFHE-rs [`strings`](`crate::strings`) module documentation!
+///
+/// # Special module attributes
+/// cbindgen:ignore
+pub mod strings;
+
 #[cfg(feature = "__wasm_api")]
 /// cbindgen:ignore
 mod js_on_wasm_api;
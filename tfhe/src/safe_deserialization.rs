@@ -287,3 +287,109 @@ mod test_integer {
         assert_eq!(&msg[..], &dec);
     }
 }
+
+#[cfg(all(test, feature = "integer"))]
+mod test_strings {
+    use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
+    use crate::high_level_api::{generate_keys, ConfigBuilder};
+    use crate::prelude::{FheDecrypt, FheTryEncrypt};
+    use crate::safe_deserialization::{safe_deserialize_conformant, safe_serialize};
+    use crate::shortint::parameters::{
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    };
+    use crate::strings::FheStringConformanceParams;
+    use crate::{CompactFheAsciiString, CompactPublicKey, FheAsciiString};
+
+    #[test]
+    fn safe_desererialization_string() {
+        let config = ConfigBuilder::default().build();
+
+        let (client_key, _server_key) = generate_keys(config);
+
+        let msg = "tfhe";
+
+        let ct = FheAsciiString::try_encrypt_with_padding(msg, 2, &client_key).unwrap();
+
+        let mut buffer = vec![];
+
+        safe_serialize(&ct, &mut buffer, 1 << 40).unwrap();
+
+        let param_set = |num_chars_constraint| {
+            FheStringConformanceParams::from_pbs_parameters(
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+                num_chars_constraint,
+            )
+        };
+
+        for parameter_set in [
+            FheStringConformanceParams::from_pbs_parameters(
+                PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+                ListSizeConstraint::exact_size(6),
+            ),
+            param_set(ListSizeConstraint::exact_size(4)),
+            param_set(ListSizeConstraint::try_size_in_range(0, 5).unwrap()),
+        ]
+        .iter()
+        {
+            assert!(safe_deserialize_conformant::<FheAsciiString>(
+                buffer.as_slice(),
+                1 << 30,
+                parameter_set,
+            )
+            .is_err());
+        }
+
+        let ct2 = safe_deserialize_conformant::<FheAsciiString>(
+            buffer.as_slice(),
+            1 << 30,
+            &param_set(ListSizeConstraint::try_size_in_range(1, 6).unwrap()),
+        )
+        .unwrap();
+
+        let dec: String = ct2.decrypt(&client_key);
+        assert_eq!(msg, dec);
+    }
+
+    #[test]
+    fn safe_desererialization_compact_string() {
+        let config = ConfigBuilder::default().build();
+
+        let (client_key, _server_key) = generate_keys(config);
+
+        let public_key = CompactPublicKey::new(&client_key);
+
+        let msg = "fhe";
+
+        let ct = CompactFheAsciiString::try_encrypt(msg, &public_key).unwrap();
+
+        let mut buffer = vec![];
+
+        safe_serialize(&ct, &mut buffer, 1 << 40).unwrap();
+
+        let param_set = |num_chars_constraint| {
+            FheStringConformanceParams::from_pbs_parameters(
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+                num_chars_constraint,
+            )
+        };
+
+        for parameter_set in [
+            param_set(ListSizeConstraint::exact_size(2)),
+            param_set(ListSizeConstraint::try_size_in_range(4, 8).unwrap()),
+        ]
+        .iter()
+        {
+            assert!(!ct.is_conformant(parameter_set));
+        }
+
+        let ct2 = safe_deserialize_conformant::<CompactFheAsciiString>(
+            buffer.as_slice(),
+            1 << 20,
+            &param_set(ListSizeConstraint::try_size_in_range(0, 8).unwrap()),
+        )
+        .unwrap();
+
+        let dec: String = ct2.expand().decrypt(&client_key);
+        assert_eq!(msg, dec);
+    }
+}
@@ -0,0 +1,243 @@
+//! This module implements the encrypted string structures.
+use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
+use crate::integer::ciphertext::{CompactCiphertextList, CompressedRadixCiphertext};
+use crate::integer::parameters::{
+    RadixCiphertextConformanceParams, RadixCompactCiphertextListConformanceParams,
+};
+use crate::integer::RadixCiphertext;
+use crate::named::Named;
+use crate::shortint::parameters::CiphertextConformanceParams;
+use crate::shortint::PBSParameters;
+use serde::{Deserialize, Serialize};
+
+/// Structure to store the expected properties of an encrypted string
+/// Can be used on a server to check if client inputs are well formed
+/// before running a computation on them
+///
+/// `num_chars_constraint` bounds the number of encrypted characters, padding included.
+#[derive(Copy, Clone)]
+pub struct FheStringConformanceParams {
+    pub shortint_params: CiphertextConformanceParams,
+    pub num_blocks_per_char: usize,
+    pub num_chars_constraint: ListSizeConstraint,
+}
+
+impl FheStringConformanceParams {
+    pub fn from_pbs_parameters<P: Into<PBSParameters>>(
+        params: P,
+        num_chars_constraint: ListSizeConstraint,
+    ) -> Self {
+        let params: PBSParameters = params.into();
+        Self {
+            shortint_params: params.to_shortint_conformance_param(),
+            num_blocks_per_char: super::num_blocks_per_char(params.message_modulus()),
+            num_chars_constraint,
+        }
+    }
+
+    fn char_conformance_params(&self) -> RadixCiphertextConformanceParams {
+        RadixCiphertextConformanceParams {
+            shortint_params: self.shortint_params,
+            num_blocks_per_integer: self.num_blocks_per_char,
+        }
+    }
+
+    fn compact_list_conformance_params(&self) -> RadixCompactCiphertextListConformanceParams {
+        self.char_conformance_params()
+            .to_ct_list_conformance_parameters(self.num_chars_constraint)
+    }
+}
+
+/// An encrypted ASCII character.
+///
+/// The character is stored as a radix ciphertext holding (at least) 8 bits of message.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct FheAsciiChar {
+    pub(crate) ciphertext: RadixCiphertext,
+}
+
+impl FheAsciiChar {
+    pub fn new(ciphertext: RadixCiphertext) -> Self {
+        Self { ciphertext }
+    }
+
+    pub fn ciphertext(&self) -> &RadixCiphertext {
+        &self.ciphertext
+    }
+
+    pub fn into_ciphertext(self) -> RadixCiphertext {
+        self.ciphertext
+    }
+}
+
+impl Named for FheAsciiChar {
+    const NAME: &'static str = "strings::FheAsciiChar";
+}
+
+impl ParameterSetConformant for FheAsciiChar {
+    type ParameterSet = RadixCiphertextConformanceParams;
+
+    fn is_conformant(&self, params: &RadixCiphertextConformanceParams) -> bool {
+        self.ciphertext.is_conformant(params)
+    }
+}
+
+/// An encrypted ASCII string.
+///
+/// The characters are stored in reading order (first character first).
+///
+/// When `padded` is true, the string may end with any number of encrypted null characters, which
+/// are not part of its content. This allows to hide the real length of the string, at the cost
+/// of operations having to be done on all characters, including the padding ones.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct FheString {
+    pub(crate) chars: Vec<FheAsciiChar>,
+    pub(crate) padded: bool,
+}
+
+impl Named for FheString {
+    const NAME: &'static str = "strings::FheString";
+}
+
+impl ParameterSetConformant for FheString {
+    type ParameterSet = FheStringConformanceParams;
+
+    fn is_conformant(&self, params: &FheStringConformanceParams) -> bool {
+        let char_params = params.char_conformance_params();
+
+        params.num_chars_constraint.is_valid(self.chars.len())
+            && self
+                .chars
+                .iter()
+                .all(|char| char.is_conformant(&char_params))
+    }
+}
+
+impl FheString {
+    /// Creates a string from its encrypted characters.
+    ///
+    /// `padded` must be true if some of the last characters may encrypt `'\0'`.
+    pub fn new(chars: Vec<FheAsciiChar>, padded: bool) -> Self {
+        Self { chars, padded }
+    }
+
+    pub fn chars(&self) -> &[FheAsciiChar] {
+        &self.chars
+    }
+
+    pub fn into_chars(self) -> Vec<FheAsciiChar> {
+        self.chars
+    }
+
+    /// Returns the number of encrypted characters, padding included.
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    /// Returns whether the string may end with encrypted null characters.
+    pub fn is_padded(&self) -> bool {
+        self.padded
+    }
+}
+
+/// A compressed encrypted ASCII string.
+///
+/// It has to be decompressed into a [FheString] to be used in computations.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CompressedFheString {
+    pub(crate) chars: Vec<CompressedRadixCiphertext>,
+    pub(crate) padded: bool,
+}
+
+impl Named for CompressedFheString {
+    const NAME: &'static str = "strings::CompressedFheString";
+}
+
+impl ParameterSetConformant for CompressedFheString {
+    type ParameterSet = FheStringConformanceParams;
+
+    fn is_conformant(&self, params: &FheStringConformanceParams) -> bool {
+        let char_params = params.char_conformance_params();
+
+        params.num_chars_constraint.is_valid(self.chars.len())
+            && self
+                .chars
+                .iter()
+                .all(|char| char.is_conformant(&char_params))
+    }
+}
+
+impl CompressedFheString {
+    pub fn decompress(self) -> FheString {
+        FheString::from(self)
+    }
+}
+
+impl From<CompressedFheString> for FheString {
+    fn from(compressed: CompressedFheString) -> Self {
+        Self {
+            chars: compressed
+                .chars
+                .into_iter()
+                .map(|ct| FheAsciiChar::new(ct.into()))
+                .collect(),
+            padded: compressed.padded,
+        }
+    }
+}
+
+/// A compact encrypted ASCII string, encrypted with a
+/// [CompactPublicKey](super::CompactPublicKey).
+///
+/// All the characters are packed in a single [CompactCiphertextList], which makes it the smallest
+/// form to send a string to a server. It has to be expanded into a [FheString] to be used in
+/// computations.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CompactFheString {
+    pub(crate) list: CompactCiphertextList,
+    pub(crate) padded: bool,
+}
+
+impl Named for CompactFheString {
+    const NAME: &'static str = "strings::CompactFheString";
+}
+
+impl ParameterSetConformant for CompactFheString {
+    type ParameterSet = FheStringConformanceParams;
+
+    fn is_conformant(&self, params: &FheStringConformanceParams) -> bool {
+        self.list
+            .is_conformant(&params.compact_list_conformance_params())
+    }
+}
+
+impl CompactFheString {
+    pub fn expand(&self) -> FheString {
+        let chars = self
+            .list
+            .expand()
+            .into_iter()
+            .map(FheAsciiChar::new)
+            .collect();
+
+        FheString::new(chars, self.padded)
+    }
+
+    /// Returns the number of encrypted characters, padding included.
+    pub fn len(&self) -> usize {
+        self.list.ciphertext_count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether the string may end with encrypted null characters.
+    pub fn is_padded(&self) -> bool {
+        self.padded
+    }
+}
@@ -0,0 +1,158 @@
+//! Definition of the client key for encrypted strings
+
+use super::ciphertext::{CompressedFheString, FheAsciiChar, FheString};
+use super::{num_blocks_per_char, padded_ascii_bytes};
+use crate::integer::BooleanBlock;
+use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
+
+/// Client key for encrypted strings.
+///
+/// This key is a simple wrapper of the integer [ClientKey](crate::integer::ClientKey)
+/// that encrypts strings character by character. Like the [ServerKey](super::ServerKey), it can
+/// either own the integer key or borrow it.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// use tfhe::strings::ClientKey;
+///
+/// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+///
+/// let ct = cks.encrypt_ascii("hello", Some(3));
+/// assert_eq!(ct.len(), 8);
+///
+/// let dec = cks.decrypt_ascii(&ct);
+/// assert_eq!(dec, "hello");
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ClientKey<T = crate::integer::ClientKey>
+where
+    T: Borrow<crate::integer::ClientKey>,
+{
+    key: T,
+}
+
+impl From<crate::integer::ClientKey> for ClientKey {
+    fn from(key: crate::integer::ClientKey) -> Self {
+        Self { key }
+    }
+}
+
+impl<'a> From<&'a crate::integer::ClientKey> for ClientKey<&'a crate::integer::ClientKey> {
+    fn from(key: &'a crate::integer::ClientKey) -> Self {
+        Self { key }
+    }
+}
+
+impl From<ClientKey> for crate::integer::ClientKey {
+    fn from(key: ClientKey) -> Self {
+        key.key
+    }
+}
+
+impl<T> AsRef<crate::integer::ClientKey> for ClientKey<T>
+where
+    T: Borrow<crate::integer::ClientKey>,
+{
+    fn as_ref(&self) -> &crate::integer::ClientKey {
+        self.key.borrow()
+    }
+}
+
+/// Checks that the string can be encrypted, that is, it only contains ASCII characters and no
+/// null character (which is reserved for padding).
+pub(crate) fn is_valid_ascii(str: &str) -> bool {
+    str.bytes().all(|byte| byte.is_ascii() && byte != 0)
+}
+
+impl ClientKey {
+    pub fn new<P>(parameter_set: P) -> Self
+    where
+        P: TryInto<crate::shortint::parameters::ShortintParameterSet>,
+        <P as TryInto<crate::shortint::parameters::ShortintParameterSet>>::Error: std::fmt::Debug,
+    {
+        Self {
+            key: crate::integer::ClientKey::new(parameter_set),
+        }
+    }
+}
+
+impl<T> ClientKey<T>
+where
+    T: Borrow<crate::integer::ClientKey>,
+{
+    fn integer_key(&self) -> &crate::integer::ClientKey {
+        self.key.borrow()
+    }
+
+    /// Returns the number of blocks used to encrypt one character.
+    pub fn num_blocks_per_char(&self) -> usize {
+        num_blocks_per_char(self.integer_key().parameters().message_modulus())
+    }
+
+    /// Encrypts an ASCII string, appending `padding` encrypted null characters if given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string contains non ASCII characters or null characters.
+    pub fn encrypt_ascii(&self, str: &str, padding: Option<u32>) -> FheString {
+        let num_blocks = self.num_blocks_per_char();
+        let (bytes, padded) = padded_ascii_bytes(str, padding);
+
+        let chars = bytes
+            .map(|byte| FheAsciiChar::new(self.integer_key().encrypt_radix(byte, num_blocks)))
+            .collect();
+
+        FheString::new(chars, padded)
+    }
+
+    /// Encrypts an ASCII string into a compressed string, appending `padding` encrypted null
+    /// characters if given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string contains non ASCII characters or null characters.
+    pub fn encrypt_ascii_compressed(&self, str: &str, padding: Option<u32>) -> CompressedFheString {
+        let num_blocks = self.num_blocks_per_char();
+        let (bytes, padded) = padded_ascii_bytes(str, padding);
+
+        let chars = bytes
+            .map(|byte| {
+                self.integer_key()
+                    .encrypt_radix_compressed(byte, num_blocks)
+            })
+            .collect();
+
+        CompressedFheString { chars, padded }
+    }
+
+    /// Decrypts a string, the padding characters are not part of the result.
+    pub fn decrypt_ascii(&self, str: &FheString) -> String {
+        let bytes = str
+            .chars()
+            .iter()
+            .map(|char| self.decrypt_char(char))
+            .take_while(|byte| *byte != 0)
+            .collect::<Vec<_>>();
+
+        String::from_utf8(bytes).expect("Decrypted string is not valid ASCII")
+    }
+
+    pub fn encrypt_char(&self, char: u8) -> FheAsciiChar {
+        assert!(char.is_ascii(), "The character must be ASCII");
+        FheAsciiChar::new(
+            self.integer_key()
+                .encrypt_radix(char, self.num_blocks_per_char()),
+        )
+    }
+
+    pub fn decrypt_char(&self, char: &FheAsciiChar) -> u8 {
+        self.integer_key().decrypt_radix(&char.ciphertext)
+    }
+
+    pub fn decrypt_bool(&self, ct: &BooleanBlock) -> bool {
+        self.integer_key().decrypt_bool(ct)
+    }
+}
@@ -0,0 +1,60 @@
+//! Module with the definition of a key-value table indexed by strings.
+use super::ciphertext::FheString;
+use crate::integer::{IntegerCiphertext, RadixCiphertext};
+use serde::{Deserialize, Serialize};
+
+/// A table mapping strings to encrypted integers, which can be queried obliviously with an
+/// encrypted string (see [ServerKey::lookup](super::ServerKey::lookup)).
+///
+/// Keys can be encrypted strings or, when the table itself is public, trivially encrypted ones
+/// (see [ServerKey::create_trivial_ascii](super::ServerKey::create_trivial_ascii)). They are
+/// expected to be distinct.
+///
+/// All the values have the same number of blocks.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct FheStringMap {
+    pub(crate) entries: Vec<(FheString, RadixCiphertext)>,
+    pub(crate) num_blocks: usize,
+}
+
+impl FheStringMap {
+    /// Creates an empty table whose values have `num_blocks` blocks.
+    pub fn new(num_blocks: usize) -> Self {
+        Self {
+            entries: Vec::new(),
+            num_blocks,
+        }
+    }
+
+    /// Adds an entry to the table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not have the number of blocks of the table.
+    pub fn insert(&mut self, key: FheString, value: RadixCiphertext) {
+        assert_eq!(
+            value.blocks().len(),
+            self.num_blocks,
+            "The value must have {} blocks",
+            self.num_blocks
+        );
+        self.entries.push((key, value));
+    }
+
+    pub fn entries(&self) -> &[(FheString, RadixCiphertext)] {
+        &self.entries
+    }
+
+    /// Returns the number of blocks of the values.
+    pub fn num_blocks(&self) -> usize {
+        self.num_blocks
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
@@ -0,0 +1,107 @@
+//! # Description
+//!
+//! This module makes it possible to work on encrypted ASCII strings.
+//!
+//! A string is encrypted character by character, each character being a radix ciphertext holding
+//! 8 bits of message. Optionally, a string can be padded with encrypted null characters
+//! (`'\0'`) so that its real length is hidden, every character after the first null character
+//! then being a null character as well.
+//!
+//! By default, operations run on all the characters of their inputs, so that their running time
+//! only depends on the (padded) lengths of the strings. When some characters are trivial
+//! ciphertexts, e.g. when comparing an encrypted string to a public one, the
+//! [ExecutionMode::Optimized] mode of the [ServerKey] skips the work that these characters make
+//! unnecessary.
+//!
+//! # Quick Example
+//!
+//! ```rust
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+//! use tfhe::strings::gen_keys;
+//!
+//! let (client_key, server_key) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+//!
+//! // "tfhe" is encrypted with 2 additional padding characters
+//! let ct_1 = client_key.encrypt_ascii("tfhe", Some(2));
+//! let ct_2 = client_key.encrypt_ascii("tfhe", None);
+//!
+//! let ct_res = server_key.eq(&ct_1, &ct_2);
+//!
+//! assert!(client_key.decrypt_bool(&ct_res));
+//! assert_eq!(client_key.decrypt_ascii(&ct_1), "tfhe");
+//! ```
+
+pub mod ciphertext;
+pub mod client_key;
+pub mod map;
+pub mod public_key;
+pub mod server_key;
+pub mod sha256;
+#[cfg(test)]
+mod tests;
+
+pub use ciphertext::{
+    CompactFheString, CompressedFheString, FheAsciiChar, FheString, FheStringConformanceParams,
+};
+pub use client_key::ClientKey;
+pub use map::FheStringMap;
+pub use public_key::{CompactPublicKey, CompressedPublicKey, PublicKey};
+pub use server_key::{ExecutionMode, ServerKey};
+
+use crate::integer::IntegerKeyKind;
+use crate::shortint::MessageModulus;
+
+/// Number of bits of message in an ASCII character.
+pub(crate) const CHAR_BITS: u32 = 8;
+
+/// Returns the number of blocks needed to encrypt a character with the given message modulus.
+pub(crate) fn num_blocks_per_char(message_modulus: MessageModulus) -> usize {
+    let bits_per_block = message_modulus.0.ilog2();
+    assert!(bits_per_block > 0, "MessageModulus must be at least 2");
+    ((CHAR_BITS + bits_per_block - 1) / bits_per_block) as usize
+}
+
+/// Returns the bytes to encrypt for the string followed by `padding` null characters, along
+/// with whether the resulting string is padded.
+///
+/// # Panics
+///
+/// Panics if the string contains non ASCII characters or null characters.
+pub(crate) fn padded_ascii_bytes(
+    str: &str,
+    padding: Option<u32>,
+) -> (impl Iterator<Item = u8> + '_, bool) {
+    assert!(
+        client_key::is_valid_ascii(str),
+        "The string must only contain non null ASCII characters"
+    );
+    let padding = padding.unwrap_or(0);
+
+    (
+        str.bytes()
+            .chain(std::iter::repeat(0).take(padding as usize)),
+        padding > 0,
+    )
+}
+
+/// Generate a couple of client and server keys with given parameters.
+///
+/// * the client key is used to encrypt and decrypt strings and has to be kept secret;
+/// * the server key is used to perform homomorphic operations on encrypted strings on the server
+///   side.
+///
+/// ```rust
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// use tfhe::strings::gen_keys;
+///
+/// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+/// ```
+pub fn gen_keys<P>(parameters_set: P) -> (ClientKey, ServerKey)
+where
+    P: TryInto<crate::shortint::parameters::ShortintParameterSet>,
+    <P as TryInto<crate::shortint::parameters::ShortintParameterSet>>::Error: std::fmt::Debug,
+{
+    let (cks, sks) = crate::integer::gen_keys(parameters_set, IntegerKeyKind::Radix);
+
+    (ClientKey::from(cks), ServerKey::new(sks))
+}
@@ -0,0 +1,209 @@
+//! Definition of the public keys for encrypted strings
+//!
+//! Like the [ClientKey](super::ClientKey), these keys are simple wrappers of their integer
+//! counterparts, that can either own or borrow them.
+
+use super::ciphertext::{CompactFheString, FheAsciiChar, FheString};
+use super::{num_blocks_per_char, padded_ascii_bytes};
+use crate::integer::ClientKey as IntegerClientKey;
+use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
+
+/// Public key to encrypt strings.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// use tfhe::strings::{gen_keys, PublicKey};
+///
+/// let (cks, _sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+/// let pks = PublicKey::new(&cks);
+///
+/// let ct = pks.encrypt_ascii("hello", Some(2));
+/// assert_eq!(ct.len(), 7);
+/// assert_eq!(cks.decrypt_ascii(&ct), "hello");
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PublicKey<T = crate::integer::PublicKey>
+where
+    T: Borrow<crate::integer::PublicKey>,
+{
+    key: T,
+}
+
+/// Compressed public key to encrypt strings.
+///
+/// It can be used to encrypt directly, or be decompressed into a [PublicKey].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CompressedPublicKey<T = crate::integer::CompressedPublicKey>
+where
+    T: Borrow<crate::integer::CompressedPublicKey>,
+{
+    key: T,
+}
+
+/// Compact public key to encrypt strings.
+///
+/// Strings encrypted with this key are packed in a single compact list.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::parameters_compact_pk::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+/// use tfhe::strings::{gen_keys, CompactPublicKey};
+///
+/// let (cks, _sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+/// let pks = CompactPublicKey::new(&cks);
+///
+/// let compact = pks.encrypt_ascii("hello", None);
+/// assert_eq!(compact.len(), 5);
+///
+/// let ct = compact.expand();
+/// assert_eq!(cks.decrypt_ascii(&ct), "hello");
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CompactPublicKey<T = crate::integer::public_key::CompactPublicKey>
+where
+    T: Borrow<crate::integer::public_key::CompactPublicKey>,
+{
+    key: T,
+}
+
+impl PublicKey {
+    pub fn new<C>(client_key: &C) -> Self
+    where
+        C: AsRef<IntegerClientKey>,
+    {
+        Self {
+            key: crate::integer::PublicKey::new(client_key),
+        }
+    }
+}
+
+impl CompressedPublicKey {
+    pub fn new<C>(client_key: &C) -> Self
+    where
+        C: AsRef<IntegerClientKey>,
+    {
+        Self {
+            key: crate::integer::CompressedPublicKey::new(client_key),
+        }
+    }
+
+    pub fn decompress(self) -> PublicKey {
+        PublicKey::from(crate::integer::PublicKey::from(self.key))
+    }
+}
+
+impl CompactPublicKey {
+    pub fn new<C>(client_key: &C) -> Self
+    where
+        C: AsRef<IntegerClientKey>,
+    {
+        Self {
+            key: crate::integer::public_key::CompactPublicKey::new(client_key.as_ref()),
+        }
+    }
+}
+
+impl<T> From<T> for PublicKey<T>
+where
+    T: Borrow<crate::integer::PublicKey>,
+{
+    fn from(key: T) -> Self {
+        Self { key }
+    }
+}
+
+impl<T> From<T> for CompressedPublicKey<T>
+where
+    T: Borrow<crate::integer::CompressedPublicKey>,
+{
+    fn from(key: T) -> Self {
+        Self { key }
+    }
+}
+
+impl<T> From<T> for CompactPublicKey<T>
+where
+    T: Borrow<crate::integer::public_key::CompactPublicKey>,
+{
+    fn from(key: T) -> Self {
+        Self { key }
+    }
+}
+
+impl<T> PublicKey<T>
+where
+    T: Borrow<crate::integer::PublicKey>,
+{
+    /// Encrypts an ASCII string, appending `padding` encrypted null characters if given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string contains non ASCII characters or null characters.
+    pub fn encrypt_ascii(&self, str: &str, padding: Option<u32>) -> FheString {
+        let key = self.key.borrow();
+        let num_blocks = num_blocks_per_char(key.parameters().message_modulus());
+        let (bytes, padded) = padded_ascii_bytes(str, padding);
+
+        let chars = bytes
+            .map(|byte| FheAsciiChar::new(key.encrypt_radix(byte, num_blocks)))
+            .collect();
+
+        FheString::new(chars, padded)
+    }
+}
+
+impl<T> CompressedPublicKey<T>
+where
+    T: Borrow<crate::integer::CompressedPublicKey>,
+{
+    /// Encrypts an ASCII string, appending `padding` encrypted null characters if given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string contains non ASCII characters or null characters.
+    pub fn encrypt_ascii(&self, str: &str, padding: Option<u32>) -> FheString {
+        let key = self.key.borrow();
+        let num_blocks = num_blocks_per_char(key.parameters().message_modulus());
+        let (bytes, padded) = padded_ascii_bytes(str, padding);
+
+        let chars = bytes
+            .map(|byte| FheAsciiChar::new(key.encrypt_radix(byte, num_blocks)))
+            .collect();
+
+        FheString::new(chars, padded)
+    }
+}
+
+impl<T> CompactPublicKey<T>
+where
+    T: Borrow<crate::integer::public_key::CompactPublicKey>,
+{
+    /// Encrypts an ASCII string into a single compact list, appending `padding` encrypted null
+    /// characters if given.
+    ///
+    /// As a compact list cannot be empty, the empty string is encrypted as a single padding
+    /// character.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string contains non ASCII characters or null characters.
+    pub fn encrypt_ascii(&self, str: &str, padding: Option<u32>) -> CompactFheString {
+        let key = self.key.borrow();
+        let num_blocks = num_blocks_per_char(key.key.parameters.message_modulus());
+        let padding = if str.is_empty() {
+            Some(padding.unwrap_or(0).max(1))
+        } else {
+            padding
+        };
+        let (bytes, padded) = padded_ascii_bytes(str, padding);
+
+        CompactFheString {
+            list: key.encrypt_iter_radix_compact(bytes, num_blocks),
+            padded,
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use super::{ExecutionMode, ServerKey};
+use crate::integer::BooleanBlock;
+use crate::strings::ciphertext::FheString;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::ops::Range;
+
+/// Part of the comparison of two strings left to be done homomorphically.
+enum Simplified {
+    /// The ordering of the strings is known without any computation.
+    Known(Ordering),
+    /// The strings are equal outside of this range of characters (of the zero extended strings).
+    Range(Range<usize>),
+}
+
+impl<T> ServerKey<T>
+where
+    T: Borrow<crate::integer::ServerKey> + Sync,
+{
+    /// Compares two strings for equality.
+    ///
+    /// Padding characters are not part of the content, so `"ab"` and `"ab"` padded with null
+    /// characters are equal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct_1 = cks.encrypt_ascii("ab", Some(2));
+    /// let ct_2 = cks.encrypt_ascii("ab", None);
+    ///
+    /// let ct_res = sks.eq(&ct_1, &ct_2);
+    /// assert!(cks.decrypt_bool(&ct_res));
+    /// ```
+    pub fn eq(&self, lhs: &FheString, rhs: &FheString) -> BooleanBlock {
+        // Without padding, the lengths are the real lengths
+        if !lhs.is_padded() && !rhs.is_padded() && lhs.len() != rhs.len() {
+            return self.integer_key().create_trivial_boolean_block(false);
+        }
+
+        self.compare(lhs, rhs, Ordering::is_eq, |sks, lhs, rhs| {
+            sks.eq_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Compares two strings for difference.
+    ///
+    /// See [Self::eq].
+    pub fn ne(&self, lhs: &FheString, rhs: &FheString) -> BooleanBlock {
+        if !lhs.is_padded() && !rhs.is_padded() && lhs.len() != rhs.len() {
+            return self.integer_key().create_trivial_boolean_block(true);
+        }
+
+        self.compare(lhs, rhs, Ordering::is_ne, |sks, lhs, rhs| {
+            sks.ne_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Returns whether `lhs` is strictly lower than `rhs` in lexicographic order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct_1 = cks.encrypt_ascii("ab", None);
+    /// let ct_2 = cks.encrypt_ascii("abc", Some(1));
+    ///
+    /// let ct_res = sks.lt(&ct_1, &ct_2);
+    /// assert!(cks.decrypt_bool(&ct_res));
+    /// ```
+    pub fn lt(&self, lhs: &FheString, rhs: &FheString) -> BooleanBlock {
+        self.compare(lhs, rhs, Ordering::is_lt, |sks, lhs, rhs| {
+            sks.lt_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Returns whether `lhs` is lower than or equal to `rhs` in lexicographic order.
+    pub fn le(&self, lhs: &FheString, rhs: &FheString) -> BooleanBlock {
+        self.compare(lhs, rhs, Ordering::is_le, |sks, lhs, rhs| {
+            sks.le_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Returns whether `lhs` is strictly greater than `rhs` in lexicographic order.
+    pub fn gt(&self, lhs: &FheString, rhs: &FheString) -> BooleanBlock {
+        self.compare(lhs, rhs, Ordering::is_gt, |sks, lhs, rhs| {
+            sks.gt_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Returns whether `lhs` is greater than or equal to `rhs` in lexicographic order.
+    pub fn ge(&self, lhs: &FheString, rhs: &FheString) -> BooleanBlock {
+        self.compare(lhs, rhs, Ordering::is_ge, |sks, lhs, rhs| {
+            sks.ge_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Zero extends both strings to the same length and applies the comparison on their
+    /// lexicographic radix representation.
+    ///
+    /// `clear_comparison` gives the result of the comparison from the ordering of the strings,
+    /// it is used when the ordering is known without computations.
+    ///
+    /// In [ExecutionMode::Optimized] mode, the characters that are trivially equal in both
+    /// strings are skipped, and the comparison is not computed at all if the first trivially
+    /// known difference comes before any encrypted character.
+    fn compare<F>(
+        &self,
+        lhs: &FheString,
+        rhs: &FheString,
+        clear_comparison: fn(Ordering) -> bool,
+        comparison: F,
+    ) -> BooleanBlock
+    where
+        F: Fn(
+            &crate::integer::ServerKey,
+            &crate::integer::RadixCiphertext,
+            &crate::integer::RadixCiphertext,
+        ) -> BooleanBlock,
+    {
+        let len = lhs.len().max(rhs.len());
+
+        let range = match self.mode {
+            ExecutionMode::ConstantTime => 0..len,
+            ExecutionMode::Optimized => match Self::simplify(lhs, rhs) {
+                Simplified::Known(ordering) => {
+                    return self
+                        .integer_key()
+                        .create_trivial_boolean_block(clear_comparison(ordering));
+                }
+                Simplified::Range(range) => range,
+            },
+        };
+
+        if range.is_empty() {
+            return self
+                .integer_key()
+                .create_trivial_boolean_block(clear_comparison(Ordering::Equal));
+        }
+
+        let lhs = self.to_lexicographic_radix(lhs, range.clone());
+        let rhs = self.to_lexicographic_radix(rhs, range);
+
+        comparison(self.integer_key(), &lhs, &rhs)
+    }
+
+    /// Uses the trivial characters of both strings to reduce the range of characters to compare.
+    fn simplify(lhs: &FheString, rhs: &FheString) -> Simplified {
+        let len = lhs.len().max(rhs.len());
+
+        let mut start = 0;
+        while start < len {
+            match (
+                Self::trivial_char_at(lhs, start),
+                Self::trivial_char_at(rhs, start),
+            ) {
+                // Both strings end here, as only null characters can follow a null character
+                (Some(0), Some(0)) => return Simplified::Known(Ordering::Equal),
+                (Some(l), Some(r)) if l == r => start += 1,
+                (Some(l), Some(r)) => return Simplified::Known(l.cmp(&r)),
+                _ => break,
+            }
+        }
+
+        let mut end = len;
+        while end > start
+            && Self::trivial_char_at(lhs, end - 1) == Some(0)
+            && Self::trivial_char_at(rhs, end - 1) == Some(0)
+        {
+            end -= 1;
+        }
+
+        Simplified::Range(start..end)
+    }
+}
@@ -0,0 +1,267 @@
+use super::ServerKey;
+use crate::integer::{BooleanBlock, RadixCiphertext};
+use crate::strings::ciphertext::FheString;
+use crate::strings::client_key::is_valid_ascii;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+impl<T> ServerKey<T>
+where
+    T: Borrow<crate::integer::ServerKey> + Sync,
+{
+    /// Returns the Hamming distance between two strings, i.e. the number of positions at which
+    /// their characters differ.
+    ///
+    /// Strings of different lengths are compared as if the shortest one was extended with null
+    /// characters, so that each extra character counts as a difference.
+    ///
+    /// The result has enough blocks to encrypt the length of the longest string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct_1 = cks.encrypt_ascii("tfhe", None);
+    /// let ct_2 = cks.encrypt_ascii("thE", Some(1));
+    ///
+    /// let ct_res = sks.hamming(&ct_1, &ct_2);
+    /// let distance: u64 = cks.as_ref().decrypt_radix(&ct_res);
+    /// assert_eq!(distance, 3);
+    /// ```
+    pub fn hamming(&self, lhs: &FheString, rhs: &FheString) -> RadixCiphertext {
+        let sks = self.integer_key();
+        let len = lhs.len().max(rhs.len());
+
+        let differences = (0..len)
+            .into_par_iter()
+            .map(|i| match (lhs.chars().get(i), rhs.chars().get(i)) {
+                (Some(l), Some(r)) => sks.ne_parallelized(&l.ciphertext, &r.ciphertext),
+                (Some(char), None) | (None, Some(char)) => {
+                    sks.scalar_ne_parallelized(&char.ciphertext, 0u8)
+                }
+                (None, None) => unreachable!(),
+            })
+            .collect();
+
+        self.count_true(differences, len)
+    }
+
+    /// Returns the Hamming distance between an encrypted string and a clear one.
+    ///
+    /// See [Self::hamming].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` contains non ASCII characters or null characters.
+    pub fn scalar_hamming(&self, lhs: &FheString, rhs: &str) -> RadixCiphertext {
+        let sks = self.integer_key();
+        let rhs = clear_ascii_bytes(rhs);
+        let len = lhs.len().max(rhs.len());
+
+        let differences = (0..len)
+            .into_par_iter()
+            .map(|i| {
+                lhs.chars().get(i).map_or_else(
+                    // rhs characters are never null
+                    || sks.create_trivial_boolean_block(true),
+                    |l| sks.scalar_ne_parallelized(&l.ciphertext, rhs.get(i).map_or(0, |r| *r)),
+                )
+            })
+            .collect();
+
+        self.count_true(differences, len)
+    }
+
+    /// Returns the Levenshtein distance between two strings, i.e. the minimum number of
+    /// insertions, deletions and substitutions of characters needed to go from one string to
+    /// the other.
+    ///
+    /// The distance is computed with the classical dynamic programming algorithm, the cells of
+    /// each anti-diagonal of the matrix being computed in parallel. Inserting or deleting a
+    /// padding character is free, so that the padding does not change the distance.
+    ///
+    /// The result has enough blocks to encrypt the length of the longest string plus one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct_1 = cks.encrypt_ascii("kit", None);
+    /// let ct_2 = cks.encrypt_ascii("sit", Some(1));
+    ///
+    /// let ct_res = sks.levenshtein(&ct_1, &ct_2);
+    /// let distance: u64 = cks.as_ref().decrypt_radix(&ct_res);
+    /// assert_eq!(distance, 1);
+    /// ```
+    pub fn levenshtein(&self, lhs: &FheString, rhs: &FheString) -> RadixCiphertext {
+        let sks = self.integer_key();
+        // Candidate distances can exceed the final distance by one
+        let num_blocks = self.distance_num_blocks(lhs.len().max(rhs.len()) + 1);
+
+        let (lhs_costs, rhs_costs) = rayon::join(
+            || self.insertion_costs(lhs, num_blocks),
+            || self.insertion_costs(rhs, num_blocks),
+        );
+
+        self.edit_distance(&lhs_costs, &rhs_costs, num_blocks, |i, j| {
+            sks.ne_parallelized(&lhs.chars()[i].ciphertext, &rhs.chars()[j].ciphertext)
+        })
+    }
+
+    /// Returns the Levenshtein distance between an encrypted string and a clear one.
+    ///
+    /// See [Self::levenshtein].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` contains non ASCII characters or null characters.
+    pub fn scalar_levenshtein(&self, lhs: &FheString, rhs: &str) -> RadixCiphertext {
+        let sks = self.integer_key();
+        let rhs = clear_ascii_bytes(rhs);
+        let num_blocks = self.distance_num_blocks(lhs.len().max(rhs.len()) + 1);
+
+        let lhs_costs = self.insertion_costs(lhs, num_blocks);
+        let rhs_costs = vec![None; rhs.len()];
+
+        self.edit_distance(&lhs_costs, &rhs_costs, num_blocks, |i, j| {
+            sks.scalar_ne_parallelized(&lhs.chars()[i].ciphertext, rhs[j])
+        })
+    }
+
+    /// Computes the edit distance matrix one anti-diagonal at a time, and returns its last cell.
+    ///
+    /// `lhs_costs` and `rhs_costs` are the costs of inserting or deleting each character, `None`
+    /// meaning a clear cost of 1. `substitution_cost(i, j)` returns whether substituting the
+    /// `i`-th character of `lhs` with the `j`-th character of `rhs` has a cost.
+    fn edit_distance<F>(
+        &self,
+        lhs_costs: &[Option<RadixCiphertext>],
+        rhs_costs: &[Option<RadixCiphertext>],
+        num_blocks: usize,
+        substitution_cost: F,
+    ) -> RadixCiphertext
+    where
+        F: Fn(usize, usize) -> BooleanBlock + Sync,
+    {
+        let sks = self.integer_key();
+        let (n, m) = (lhs_costs.len(), rhs_costs.len());
+
+        let add_cost = |distance: &RadixCiphertext, cost: &Option<RadixCiphertext>| {
+            cost.as_ref().map_or_else(
+                || sks.scalar_add_parallelized(distance, 1u8),
+                |cost| sks.add_parallelized(distance, cost),
+            )
+        };
+
+        // distances[i][j] is the distance between the first i characters of lhs and the first j
+        // characters of rhs
+        let mut distances = vec![Vec::with_capacity(m + 1); n + 1];
+        distances[0].push(sks.create_trivial_radix(0u8, num_blocks));
+        for j in 1..=m {
+            let distance = add_cost(&distances[0][j - 1], &rhs_costs[j - 1]);
+            distances[0].push(distance);
+        }
+        for i in 1..=n {
+            let distance = add_cost(&distances[i - 1][0], &lhs_costs[i - 1]);
+            distances[i].push(distance);
+        }
+
+        // The cells of an anti-diagonal only depend on the cells of the two previous ones
+        for diagonal in 2..=n + m {
+            let rows = diagonal.saturating_sub(m).max(1)..=n.min(diagonal - 1);
+
+            let new_distances = rows
+                .clone()
+                .into_par_iter()
+                .map(|i| {
+                    let j = diagonal - i;
+
+                    let ((deletion, insertion), substitution) = rayon::join(
+                        || {
+                            rayon::join(
+                                || add_cost(&distances[i - 1][j], &lhs_costs[i - 1]),
+                                || add_cost(&distances[i][j - 1], &rhs_costs[j - 1]),
+                            )
+                        },
+                        || {
+                            let cost = substitution_cost(i - 1, j - 1).into_radix(num_blocks, sks);
+                            sks.add_parallelized(&distances[i - 1][j - 1], &cost)
+                        },
+                    );
+
+                    let distance = sks.min_parallelized(&deletion, &insertion);
+                    sks.min_parallelized(&distance, &substitution)
+                })
+                .collect::<Vec<_>>();
+
+            // Rows are visited in increasing order, so each cell is pushed at index j
+            for (i, distance) in rows.zip(new_distances) {
+                distances[i].push(distance);
+            }
+        }
+
+        distances.swap_remove(n).swap_remove(m)
+    }
+
+    /// Returns the cost of inserting or deleting each character of the string: 1 for a
+    /// character of the content, 0 for a padding character.
+    ///
+    /// The costs of the characters of a string without padding are known to be 1, `None` is
+    /// returned for them.
+    fn insertion_costs(&self, str: &FheString, num_blocks: usize) -> Vec<Option<RadixCiphertext>> {
+        let sks = self.integer_key();
+
+        if !str.is_padded() {
+            return vec![None; str.len()];
+        }
+
+        str.chars()
+            .par_iter()
+            .map(|char| {
+                let is_not_null = sks.scalar_ne_parallelized(&char.ciphertext, 0u8);
+                Some(is_not_null.into_radix(num_blocks, sks))
+            })
+            .collect()
+    }
+
+    /// Sums encrypted booleans into a radix ciphertext able to encrypt `max_value`.
+    pub(crate) fn count_true(
+        &self,
+        booleans: Vec<BooleanBlock>,
+        max_value: usize,
+    ) -> RadixCiphertext {
+        let sks = self.integer_key();
+        let num_blocks = self.distance_num_blocks(max_value);
+
+        let cts = booleans
+            .into_par_iter()
+            .map(|boolean| boolean.into_radix(num_blocks, sks))
+            .collect::<Vec<RadixCiphertext>>();
+
+        sks.sum_ciphertexts_parallelized(&cts)
+            .unwrap_or_else(|| sks.create_trivial_radix(0u8, num_blocks))
+    }
+
+    /// Returns the number of blocks needed to encrypt distances up to `max_value`.
+    pub(super) fn distance_num_blocks(&self, max_value: usize) -> usize {
+        let bits_per_block = self.integer_key().message_modulus().0.ilog2();
+        let num_bits = (usize::BITS - max_value.leading_zeros()).max(1);
+        ((num_bits + bits_per_block - 1) / bits_per_block) as usize
+    }
+}
+
+fn clear_ascii_bytes(str: &str) -> &[u8] {
+    assert!(
+        is_valid_ascii(str),
+        "The string must only contain non null ASCII characters"
+    );
+    str.as_bytes()
+}
@@ -0,0 +1,536 @@
+use super::ServerKey;
+use crate::integer::{BooleanBlock, IntegerCiphertext, RadixCiphertext};
+use crate::strings::ciphertext::{FheAsciiChar, FheString};
+use crate::strings::CHAR_BITS;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+use std::ops::RangeInclusive;
+
+/// The characters encoding the digits of an encoding, each range of characters encoding
+/// consecutive digits from the given one.
+type Alphabet = [(RangeInclusive<u8>, u8)];
+
+/// Lowercase hexadecimal digits, used for encoding.
+const HEX_LOWERCASE: &Alphabet = &[(b'0'..=b'9', 0), (b'a'..=b'f', 10)];
+
+/// Hexadecimal digits accepted when decoding.
+const HEX: &Alphabet = &[(b'0'..=b'9', 0), (b'a'..=b'f', 10), (b'A'..=b'F', 10)];
+
+/// Base64 digits, sorted by increasing values.
+const BASE64: &Alphabet = &[
+    (b'A'..=b'Z', 0),
+    (b'a'..=b'z', 26),
+    (b'0'..=b'9', 52),
+    (b'+'..=b'+', 62),
+    (b'/'..=b'/', 63),
+];
+
+const BASE64_PADDING: u8 = b'=';
+
+impl<T> ServerKey<T>
+where
+    T: Borrow<crate::integer::ServerKey> + Sync,
+{
+    /// Encodes the bytes of the string in lowercase hexadecimal, each character giving two
+    /// digits.
+    ///
+    /// Each padding character of the string gives two padding characters in the result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt_ascii("Hi", Some(1));
+    ///
+    /// let ct_res = sks.hex_encode(&ct);
+    /// assert_eq!(ct_res.len(), 6);
+    /// assert_eq!(cks.decrypt_ascii(&ct_res), "4869");
+    /// ```
+    pub fn hex_encode(&self, str: &FheString) -> FheString {
+        let sks = self.integer_key();
+
+        let chars = str
+            .chars()
+            .par_iter()
+            .flat_map(|char| {
+                let byte = &char.ciphertext;
+                let (high, low) = rayon::join(
+                    || sks.scalar_right_shift_parallelized(byte, 4u32),
+                    || sks.scalar_bitand_parallelized(byte, 0b1111u8),
+                );
+                let (mut high, mut low) = rayon::join(
+                    || self.encode_digit(&high, HEX_LOWERCASE),
+                    || self.encode_digit(&low, HEX_LOWERCASE),
+                );
+
+                if str.is_padded() {
+                    let is_not_null = sks.scalar_ne_parallelized(byte, 0u8);
+                    sks.zero_out_if_condition_is_false(&mut high, is_not_null.as_ref());
+                    sks.zero_out_if_condition_is_false(&mut low, is_not_null.as_ref());
+                }
+
+                vec![FheAsciiChar::new(high), FheAsciiChar::new(low)]
+            })
+            .collect();
+
+        FheString::new(chars, str.is_padded())
+    }
+
+    /// Decodes a string of hexadecimal digits, lowercase and uppercase ones being accepted.
+    ///
+    /// Returns the decoded string, along with an encrypted boolean telling whether the input
+    /// was valid. The input is invalid if it has an odd number of digits or contains a
+    /// character that is not a digit. As the result is itself a string, the input is also
+    /// invalid if it encodes null or non ASCII characters.
+    ///
+    /// The result has half as many characters as the input, rounded up. It is padded, and all
+    /// its characters are null if the input is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt_ascii("4869", None);
+    ///
+    /// let (ct_res, is_valid) = sks.hex_decode(&ct);
+    /// assert!(cks.decrypt_bool(&is_valid));
+    /// assert_eq!(cks.decrypt_ascii(&ct_res), "Hi");
+    ///
+    /// let ct = cks.encrypt_ascii("486", None);
+    ///
+    /// let (ct_res, is_valid) = sks.hex_decode(&ct);
+    /// assert!(!cks.decrypt_bool(&is_valid));
+    /// assert_eq!(cks.decrypt_ascii(&ct_res), "");
+    /// ```
+    pub fn hex_decode(&self, str: &FheString) -> (FheString, BooleanBlock) {
+        let sks = self.integer_key();
+        let num_bytes = (str.len() + 1) / 2;
+        let is_null = self.null_flags(str, 2 * num_bytes);
+
+        let (bytes, is_valid): (Vec<_>, Vec<_>) = (0..num_bytes)
+            .into_par_iter()
+            .map(|i| {
+                let ((high, is_high_digit), (low, is_low_digit)) = rayon::join(
+                    || self.decode_digit(&self.char_or_null(str, 2 * i), HEX),
+                    || self.decode_digit(&self.char_or_null(str, 2 * i + 1), HEX),
+                );
+
+                let high = sks.scalar_left_shift_parallelized(&high, 4u32);
+                let byte = self.truncate_to_byte(&sks.bitor_parallelized(&high, &low));
+
+                // Both digits are padding, or they encode a valid character
+                let is_valid_byte = self.all_true(vec![
+                    self.any_true(is_high_digit),
+                    self.any_true(is_low_digit),
+                    self.is_in_range(&byte, 1..=0x7f),
+                ]);
+                let is_valid = sks.boolean_bitor(&is_null[2 * i], &is_valid_byte);
+
+                ((byte, is_null[2 * i].clone()), is_valid)
+            })
+            .unzip();
+
+        let is_valid = self.all_true(is_valid);
+        (self.valid_content(bytes, &is_valid), is_valid)
+    }
+
+    /// Encodes the bytes of the string in base64, as specified by RFC 4648.
+    ///
+    /// Each group of 3 characters gives 4 characters in the result, the last group being
+    /// completed with '=' characters. The padding characters of the string give padding
+    /// characters in the result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt_ascii("tfhe", Some(2));
+    ///
+    /// let ct_res = sks.base64_encode(&ct);
+    /// assert_eq!(ct_res.len(), 8);
+    /// assert_eq!(cks.decrypt_ascii(&ct_res), "dGZoZQ==");
+    /// ```
+    pub fn base64_encode(&self, str: &FheString) -> FheString {
+        let sks = self.integer_key();
+        let num_groups = (str.len() + 2) / 3;
+        let is_null = self.null_flags(str, 3 * num_groups);
+
+        let chars = (0..num_groups)
+            .into_par_iter()
+            .flat_map(|i| {
+                let [b0, b1, b2] =
+                    [0, 1, 2].map(|j| self.char_or_null(str, 3 * i + j).into_ciphertext());
+
+                // Split the 24 bits of the group in 4 digits of 6 bits
+                let concat = |lhs: &RadixCiphertext, lhs_bits: u8, rhs: &RadixCiphertext| {
+                    let rhs_bits = 6 - lhs_bits;
+                    let (lhs, rhs) = rayon::join(
+                        || {
+                            let lhs = sks.scalar_bitand_parallelized(lhs, (1u8 << lhs_bits) - 1);
+                            sks.scalar_left_shift_parallelized(&lhs, u32::from(rhs_bits))
+                        },
+                        || {
+                            sks.scalar_right_shift_parallelized(
+                                rhs,
+                                CHAR_BITS - u32::from(rhs_bits),
+                            )
+                        },
+                    );
+                    sks.bitor_parallelized(&lhs, &rhs)
+                };
+                let ((d0, d1), (d2, d3)) = rayon::join(
+                    || {
+                        rayon::join(
+                            || sks.scalar_right_shift_parallelized(&b0, 2u32),
+                            || concat(&b0, 2, &b1),
+                        )
+                    },
+                    || {
+                        rayon::join(
+                            || concat(&b1, 4, &b2),
+                            || sks.scalar_bitand_parallelized(&b2, 0b11_1111u8),
+                        )
+                    },
+                );
+
+                // A digit is missing if the byte it starts in is null, its value is then 0
+                let is_missing = [
+                    None,
+                    None,
+                    Some(&is_null[3 * i + 1]),
+                    Some(&is_null[3 * i + 2]),
+                ];
+                let is_not_null = str.is_padded().then(|| sks.boolean_bitnot(&is_null[3 * i]));
+                [d0, d1, d2, d3]
+                    .into_par_iter()
+                    .zip(is_missing)
+                    .map(|(digit, is_missing)| {
+                        let mut char = self.encode_digit(&digit, BASE64);
+                        if let Some(is_missing) = is_missing {
+                            let to_padding = BASE64_PADDING.wrapping_sub(b'A');
+                            char = self.linear_combination(&char, 0, &[(is_missing, to_padding)]);
+                        }
+                        if let Some(is_not_null) = &is_not_null {
+                            self.zero_out_if_false(&mut char, is_not_null);
+                        }
+                        FheAsciiChar::new(char)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        FheString::new(chars, str.is_padded())
+    }
+
+    /// Decodes a base64 string, as specified by RFC 4648.
+    ///
+    /// Returns the decoded string, along with an encrypted boolean telling whether the input
+    /// was valid. The input is invalid if its number of characters is not a multiple of 4, or
+    /// if it contains characters that are not base64 digits, the '=' characters being only
+    /// allowed at the end of the last group. As the result is itself a string, the input is
+    /// also invalid if it encodes null or non ASCII characters.
+    ///
+    /// The result has 3 characters for each group of 4 characters of the input. It is padded,
+    /// and all its characters are null if the input is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt_ascii("dGZoZQ==", None);
+    ///
+    /// let (ct_res, is_valid) = sks.base64_decode(&ct);
+    /// assert!(cks.decrypt_bool(&is_valid));
+    /// assert_eq!(cks.decrypt_ascii(&ct_res), "tfhe");
+    /// ```
+    pub fn base64_decode(&self, str: &FheString) -> (FheString, BooleanBlock) {
+        let sks = self.integer_key();
+        let num_groups = (str.len() + 3) / 4;
+        let is_null = self.null_flags(str, 4 * num_groups);
+
+        // The padding character is decoded as a zero digit
+        let alphabet = BASE64
+            .iter()
+            .cloned()
+            .chain([(BASE64_PADDING..=BASE64_PADDING, 0)])
+            .collect::<Vec<_>>();
+
+        let (groups, is_valid): (Vec<_>, Vec<_>) = (0..num_groups)
+            .into_par_iter()
+            .map(|i| {
+                let digits = (0..4)
+                    .into_par_iter()
+                    .map(|j| {
+                        let (value, mut is_digit) =
+                            self.decode_digit(&self.char_or_null(str, 4 * i + j), &alphabet);
+                        let is_padding = is_digit.pop().unwrap();
+                        (value, self.any_true(is_digit), is_padding)
+                    })
+                    .collect::<Vec<_>>();
+                let [(v0, is_digit_0, _), (v1, is_digit_1, _), (v2, is_digit_2, is_padding_2), (v3, is_digit_3, is_padding_3)]: [_; 4] =
+                    digits.try_into().unwrap();
+
+                // The group is made of 2 digits followed by "==", 3 digits followed by "=", or
+                // 4 digits, the '=' characters ending the content of the string
+                let is_last = is_null
+                    .get(4 * i + 4)
+                    .map_or_else(|| sks.create_trivial_boolean_block(true), Clone::clone);
+                let is_end_valid =
+                    sks.boolean_bitor(&sks.boolean_bitnot(&is_padding_3), &is_last);
+                let is_third_valid = sks.boolean_bitor(
+                    &is_digit_2,
+                    &sks.boolean_bitand(&is_padding_2, &is_padding_3),
+                );
+                let is_fourth_valid = sks.boolean_bitor(&is_digit_3, &is_padding_3);
+                let is_valid_group = self.all_true(vec![
+                    is_digit_0,
+                    is_digit_1,
+                    is_third_valid,
+                    is_fourth_valid,
+                    is_end_valid,
+                ]);
+                let is_valid_group = sks.boolean_bitor(&is_null[4 * i], &is_valid_group);
+
+                // Concatenate the 24 bits of the digits, the bits shifted out of a byte being
+                // dropped by the truncation
+                let concat = |lhs: &RadixCiphertext, lhs_shift: u32, rhs: &RadixCiphertext| {
+                    let (lhs, rhs) = rayon::join(
+                        || sks.scalar_left_shift_parallelized(lhs, lhs_shift),
+                        || sks.scalar_right_shift_parallelized(rhs, 6 - lhs_shift),
+                    );
+                    self.truncate_to_byte(&sks.bitor_parallelized(&lhs, &rhs))
+                };
+                let (b0, (b1, b2)) = rayon::join(
+                    || concat(&v0, 2, &v1),
+                    || rayon::join(|| concat(&v1, 4, &v2), || concat(&v2, 6, &v3)),
+                );
+
+                let is_padding = [
+                    is_null[4 * i].clone(),
+                    sks.boolean_bitor(&is_null[4 * i], &is_padding_2),
+                    sks.boolean_bitor(&is_null[4 * i], &is_padding_3),
+                ];
+
+                // The bytes that are not padding must be valid characters
+                let mut is_valid = [&b0, &b1, &b2]
+                    .into_par_iter()
+                    .zip(is_padding.par_iter())
+                    .map(|(byte, is_padding)| {
+                        sks.boolean_bitor(is_padding, &self.is_in_range(byte, 1..=0x7f))
+                    })
+                    .collect::<Vec<_>>();
+                is_valid.push(is_valid_group);
+
+                let bytes = [b0, b1, b2].into_iter().zip(is_padding).collect::<Vec<_>>();
+                (bytes, self.all_true(is_valid))
+            })
+            .unzip();
+        let bytes = groups.into_iter().flatten().collect();
+
+        let is_valid = self.all_true(is_valid);
+        (self.valid_content(bytes, &is_valid), is_valid)
+    }
+
+    /// Returns the character at `index`, or a trivial null character if it is out of bounds.
+    fn char_or_null(&self, str: &FheString, index: usize) -> FheAsciiChar {
+        str.chars()
+            .get(index)
+            .map_or_else(|| self.create_trivial_char(0), Clone::clone)
+    }
+
+    /// Returns whether each of the first `len` characters of the zero extended string is null.
+    fn null_flags(&self, str: &FheString, len: usize) -> Vec<BooleanBlock> {
+        let sks = self.integer_key();
+
+        (0..len)
+            .into_par_iter()
+            .map(|i| match str.chars().get(i) {
+                Some(char) if str.is_padded() => sks.scalar_eq_parallelized(&char.ciphertext, 0u8),
+                Some(_) => sks.create_trivial_boolean_block(false),
+                None => sks.create_trivial_boolean_block(true),
+            })
+            .collect()
+    }
+
+    /// Returns the character encoding the `digit`, which must be one of the digits of the
+    /// `alphabet`.
+    fn encode_digit(&self, digit: &RadixCiphertext, alphabet: &Alphabet) -> RadixCiphertext {
+        let sks = self.integer_key();
+        let offset = |(chars, first_digit): &(RangeInclusive<u8>, u8)| {
+            chars.start().wrapping_sub(*first_digit)
+        };
+
+        // Each range of digits adds the difference between its offset and the previous one
+        let terms = alphabet
+            .par_windows(2)
+            .map(|ranges| {
+                let is_in_range = sks.scalar_ge_parallelized(digit, ranges[1].1);
+                (
+                    is_in_range,
+                    offset(&ranges[1]).wrapping_sub(offset(&ranges[0])),
+                )
+            })
+            .collect::<Vec<_>>();
+        let terms = terms
+            .iter()
+            .map(|(is_in_range, value)| (is_in_range, *value))
+            .collect::<Vec<_>>();
+
+        self.linear_combination(digit, offset(&alphabet[0]), &terms)
+    }
+
+    /// Returns the digit encoded by the character, along with whether the character is in each
+    /// range of the `alphabet`.
+    ///
+    /// The returned digit is zero if the character is null, and is meaningless if the character
+    /// is not part of the alphabet.
+    fn decode_digit(
+        &self,
+        char: &FheAsciiChar,
+        alphabet: &Alphabet,
+    ) -> (RadixCiphertext, Vec<BooleanBlock>) {
+        let is_in_ranges = alphabet
+            .par_iter()
+            .map(|(chars, _)| self.is_in_range(&char.ciphertext, chars.clone()))
+            .collect::<Vec<_>>();
+
+        let terms = is_in_ranges
+            .iter()
+            .zip(alphabet)
+            .map(|(is_in_range, (chars, first_digit))| {
+                (is_in_range, first_digit.wrapping_sub(*chars.start()))
+            })
+            .collect::<Vec<_>>();
+        let digit = self.linear_combination(&char.ciphertext, 0, &terms);
+
+        (digit, is_in_ranges)
+    }
+
+    /// Computes `ct + constant + sum(scalar * boolean)` modulo 256, for the boolean and scalar
+    /// pairs of `terms`.
+    fn linear_combination(
+        &self,
+        ct: &RadixCiphertext,
+        constant: u8,
+        terms: &[(&BooleanBlock, u8)],
+    ) -> RadixCiphertext {
+        let sks = self.integer_key();
+        let num_blocks = self.num_blocks_per_char();
+
+        let mut cts = terms
+            .par_iter()
+            .map(|(boolean, scalar)| self.scaled_boolean(boolean, *scalar))
+            .collect::<Vec<_>>();
+        cts.push(ct.clone());
+        cts.push(sks.create_trivial_radix(constant, num_blocks));
+
+        let sum = sks.sum_ciphertexts_parallelized(&cts).unwrap();
+        self.truncate_to_byte(&sum)
+    }
+
+    /// Returns a character radix ciphertext encrypting `scalar` if the boolean is true, and 0
+    /// otherwise.
+    fn scaled_boolean(&self, boolean: &BooleanBlock, scalar: u8) -> RadixCiphertext {
+        let shortint_key = &self.integer_key().key;
+        let modulus = shortint_key.message_modulus.0 as u64;
+
+        let blocks = (0..self.num_blocks_per_char() as u32)
+            .into_par_iter()
+            .map(|k| {
+                let digit = (u64::from(scalar) / modulus.pow(k)) % modulus;
+                if digit == 0 {
+                    shortint_key.create_trivial(0)
+                } else {
+                    let lut = shortint_key.generate_lookup_table(|x| u64::from(x != 0) * digit);
+                    shortint_key.apply_lookup_table(boolean.as_ref(), &lut)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from_blocks(blocks)
+    }
+
+    /// Returns whether the value of the character is in the range.
+    pub(super) fn is_in_range(
+        &self,
+        ct: &RadixCiphertext,
+        range: RangeInclusive<u8>,
+    ) -> BooleanBlock {
+        let sks = self.integer_key();
+        let (start, end) = range.into_inner();
+
+        if start == end {
+            return sks.scalar_eq_parallelized(ct, start);
+        }
+
+        let (is_after_start, is_before_end) = rayon::join(
+            || sks.scalar_ge_parallelized(ct, start),
+            || sks.scalar_le_parallelized(ct, end),
+        );
+        sks.boolean_bitand(&is_after_start, &is_before_end)
+    }
+
+    /// Drops the bits of the ciphertext above the 8 bits of a character, when the blocks of a
+    /// character have more bits.
+    fn truncate_to_byte(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let bits_per_block = self.integer_key().message_modulus().0.ilog2();
+        if bits_per_block * ct.blocks().len() as u32 > CHAR_BITS {
+            self.integer_key().scalar_bitand_parallelized(ct, u8::MAX)
+        } else {
+            ct.clone()
+        }
+    }
+
+    pub(super) fn all_true(&self, booleans: Vec<BooleanBlock>) -> BooleanBlock {
+        let sks = self.integer_key();
+        booleans
+            .into_par_iter()
+            .reduce_with(|lhs, rhs| sks.boolean_bitand(&lhs, &rhs))
+            .unwrap_or_else(|| sks.create_trivial_boolean_block(true))
+    }
+
+    pub(super) fn any_true(&self, booleans: Vec<BooleanBlock>) -> BooleanBlock {
+        let sks = self.integer_key();
+        booleans
+            .into_par_iter()
+            .reduce_with(|lhs, rhs| sks.boolean_bitor(&lhs, &rhs))
+            .unwrap_or_else(|| sks.create_trivial_boolean_block(false))
+    }
+
+    /// Builds the decoded string from its bytes and whether each of them is padding, all the
+    /// bytes being zeroed if the input is invalid.
+    fn valid_content(
+        &self,
+        bytes: Vec<(RadixCiphertext, BooleanBlock)>,
+        is_valid: &BooleanBlock,
+    ) -> FheString {
+        let sks = self.integer_key();
+
+        let chars = bytes
+            .into_par_iter()
+            .map(|(mut byte, is_padding)| {
+                let is_kept = sks.boolean_bitand(is_valid, &sks.boolean_bitnot(&is_padding));
+                self.zero_out_if_false(&mut byte, &is_kept);
+                FheAsciiChar::new(byte)
+            })
+            .collect();
+
+        FheString::new(chars, true)
+    }
+}
@@ -0,0 +1,75 @@
+use super::ServerKey;
+use crate::integer::{BooleanBlock, RadixCiphertext};
+use crate::strings::ciphertext::FheString;
+use crate::strings::map::FheStringMap;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+impl<T> ServerKey<T>
+where
+    T: Borrow<crate::integer::ServerKey> + Sync,
+{
+    /// Returns the value associated to the `query` in the table, along with whether the query
+    /// was found.
+    ///
+    /// The query is compared to all the keys in parallel, and the values are summed after
+    /// zeroing the ones whose key differs, so that the matching entry is not revealed. The
+    /// returned value is zero if the query is not found.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::{gen_keys, FheStringMap};
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // A public table, with encrypted values
+    /// let mut map = FheStringMap::new(4);
+    /// map.insert(
+    ///     sks.create_trivial_ascii("alice"),
+    ///     cks.as_ref().encrypt_radix(12u8, 4),
+    /// );
+    /// map.insert(
+    ///     sks.create_trivial_ascii("bob"),
+    ///     cks.as_ref().encrypt_radix(34u8, 4),
+    /// );
+    ///
+    /// let query = cks.encrypt_ascii("bob", Some(2));
+    ///
+    /// let (ct_res, found) = sks.lookup(&map, &query);
+    /// assert!(cks.decrypt_bool(&found));
+    /// let value: u8 = cks.as_ref().decrypt_radix(&ct_res);
+    /// assert_eq!(value, 34);
+    /// ```
+    pub fn lookup(&self, map: &FheStringMap, query: &FheString) -> (RadixCiphertext, BooleanBlock) {
+        let sks = self.integer_key();
+
+        let (values, is_found): (Vec<_>, Vec<_>) = map
+            .entries()
+            .par_iter()
+            .map(|(key, value)| {
+                let is_match = self.eq(key, query);
+                let mut value = value.clone();
+                sks.zero_out_if_condition_is_false(&mut value, is_match.as_ref());
+                (value, is_match)
+            })
+            .unzip();
+
+        // As the keys are distinct, at most one of the values is not zeroed
+        let (value, is_found) = rayon::join(
+            || {
+                sks.sum_ciphertexts_parallelized(&values)
+                    .unwrap_or_else(|| sks.create_trivial_radix(0u8, map.num_blocks()))
+            },
+            || {
+                is_found
+                    .into_par_iter()
+                    .reduce_with(|lhs, rhs| sks.boolean_bitor(&lhs, &rhs))
+                    .unwrap_or_else(|| sks.create_trivial_boolean_block(false))
+            },
+        );
+
+        (value, is_found)
+    }
+}
@@ -0,0 +1,186 @@
+//! Module with the definition of the ServerKey for encrypted strings.
+mod comp;
+mod distance;
+mod encoding;
+mod lookup;
+mod pad;
+mod pattern;
+mod sort;
+mod substring;
+mod tokenizer;
+
+use super::ciphertext::{FheAsciiChar, FheString, FheStringConformanceParams};
+use super::client_key::is_valid_ascii;
+use super::num_blocks_per_char;
+use crate::conformance::ListSizeConstraint;
+use crate::integer::{BooleanBlock, IntegerCiphertext, RadixCiphertext};
+use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
+use std::ops::Range;
+
+/// How operations deal with characters that are trivial ciphertexts.
+///
+/// Strings mixing public and private data (e.g. a trivially encrypted string compared to an
+/// encrypted one) contain characters whose value is known to anyone. Skipping work on them makes
+/// the running time of an operation depend on which characters are trivial.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExecutionMode {
+    /// Operations always run on all the characters of their inputs, padding included, so that
+    /// their running time only depends on the number of characters.
+    #[default]
+    ConstantTime,
+    /// Operations decrypt trivial characters (see
+    /// [RadixCiphertext::decrypt_trivial](crate::integer::RadixCiphertext::decrypt_trivial)) to
+    /// skip the computations on them when possible.
+    Optimized,
+}
+
+/// Server key for encrypted strings.
+///
+/// This key is a wrapper of the integer [ServerKey](crate::integer::ServerKey), it can either own
+/// the integer key or borrow it, which allows to use it on top of a key that is already in use
+/// elsewhere without cloning it.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::integer::gen_keys_radix;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// use tfhe::strings::{ClientKey, ServerKey};
+///
+/// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, 4);
+///
+/// let string_cks = ClientKey::from(cks.as_ref().clone());
+/// let string_sks = ServerKey::new(&sks);
+///
+/// let ct_1 = string_cks.encrypt_ascii("tfhe", None);
+/// let ct_2 = string_cks.encrypt_ascii("tfhe-rs", None);
+///
+/// let ct_res = string_sks.lt(&ct_1, &ct_2);
+/// assert!(string_cks.decrypt_bool(&ct_res));
+/// ```
+#[derive(Clone)]
+pub struct ServerKey<T = crate::integer::ServerKey>
+where
+    T: Borrow<crate::integer::ServerKey>,
+{
+    key: T,
+    mode: ExecutionMode,
+}
+
+impl<T> ServerKey<T>
+where
+    T: Borrow<crate::integer::ServerKey> + Sync,
+{
+    /// Creates a server key running operations in the default
+    /// [ExecutionMode::ConstantTime] mode.
+    pub fn new(key: T) -> Self {
+        Self::new_with_mode(key, ExecutionMode::default())
+    }
+
+    pub fn new_with_mode(key: T, mode: ExecutionMode) -> Self {
+        Self { key, mode }
+    }
+
+    pub fn execution_mode(&self) -> ExecutionMode {
+        self.mode
+    }
+
+    pub fn set_execution_mode(&mut self, mode: ExecutionMode) {
+        self.mode = mode;
+    }
+
+    /// Returns the underlying integer server key.
+    pub fn integer_key(&self) -> &crate::integer::ServerKey {
+        self.key.borrow()
+    }
+
+    pub fn into_inner(self) -> T {
+        self.key
+    }
+
+    /// Returns the number of blocks used to encrypt one character.
+    pub fn num_blocks_per_char(&self) -> usize {
+        num_blocks_per_char(self.integer_key().message_modulus())
+    }
+
+    /// Returns the parameters an encrypted string must conform to in order to be used with this
+    /// key, its number of characters (padding included) being bounded by `num_chars_constraint`.
+    pub fn conformance_params(
+        &self,
+        num_chars_constraint: ListSizeConstraint,
+    ) -> FheStringConformanceParams {
+        FheStringConformanceParams {
+            shortint_params: self.integer_key().key.conformance_params(),
+            num_blocks_per_char: self.num_blocks_per_char(),
+            num_chars_constraint,
+        }
+    }
+
+    /// Creates a trivial encryption of an ASCII string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string contains non ASCII characters or null characters.
+    pub fn create_trivial_ascii(&self, str: &str) -> FheString {
+        assert!(
+            is_valid_ascii(str),
+            "The string must only contain non null ASCII characters"
+        );
+        let chars = str
+            .bytes()
+            .map(|byte| self.create_trivial_char(byte))
+            .collect();
+
+        FheString::new(chars, false)
+    }
+
+    /// Creates a trivial encryption of an ASCII character.
+    pub fn create_trivial_char(&self, char: u8) -> FheAsciiChar {
+        FheAsciiChar::new(
+            self.integer_key()
+                .create_trivial_radix(char, self.num_blocks_per_char()),
+        )
+    }
+
+    /// Concatenates the characters in `range` of the zero extended string into a single radix
+    /// ciphertext.
+    ///
+    /// The first character is stored in the most significant blocks, so that comparing two such
+    /// radix ciphertexts compares the strings in lexicographic order.
+    pub(crate) fn to_lexicographic_radix(
+        &self,
+        str: &FheString,
+        range: Range<usize>,
+    ) -> RadixCiphertext {
+        let num_blocks = self.num_blocks_per_char();
+        let mut blocks = Vec::with_capacity(range.len() * num_blocks);
+
+        let zero = self.integer_key().key.create_trivial(0);
+        for index in range.rev() {
+            match str.chars().get(index) {
+                Some(char) => blocks.extend_from_slice(char.ciphertext.blocks()),
+                None => blocks.extend(std::iter::repeat(zero.clone()).take(num_blocks)),
+            }
+        }
+
+        RadixCiphertext::from_blocks(blocks)
+    }
+
+    /// Zeroes `ct` if `condition` is false.
+    ///
+    /// Boolean operations do not keep track of their result being 0 or 1, so the condition is
+    /// only required to encrypt a value below the message modulus.
+    pub(crate) fn zero_out_if_false(&self, ct: &mut RadixCiphertext, condition: &BooleanBlock) {
+        self.integer_key()
+            .zero_out_if_condition_equals(ct, condition.as_ref(), 0);
+    }
+
+    /// Returns the value of the character at `index` of the zero extended string if it is
+    /// trivially known.
+    pub(crate) fn trivial_char_at(str: &FheString, index: usize) -> Option<u8> {
+        str.chars()
+            .get(index)
+            .map_or(Some(0), |char| char.ciphertext.decrypt_trivial::<u8>().ok())
+    }
+}
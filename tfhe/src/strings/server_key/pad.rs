@@ -0,0 +1,202 @@
+use super::{ExecutionMode, ServerKey};
+use crate::integer::{BooleanBlock, IntegerCiphertext, RadixCiphertext};
+use crate::strings::ciphertext::{FheAsciiChar, FheString};
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+impl<T> ServerKey<T>
+where
+    T: Borrow<crate::integer::ServerKey> + Sync,
+{
+    /// Pads the string on the left with the `fill` character up to `width` characters.
+    ///
+    /// As the width may be encrypted, the result has a fixed number of characters:
+    /// `max(str.len(), max_width)`, padded with null characters after the content. Widths
+    /// greater than `max_width` are treated as `max_width`, and the string is unchanged if it
+    /// is at least `width` characters long.
+    ///
+    /// The fill character can be encrypted, or trivial (see [Self::create_trivial_char]). It is
+    /// expected not to be null.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt_ascii("42", Some(2));
+    /// let width = cks.as_ref().encrypt_radix(5u8, 2);
+    /// let fill = sks.create_trivial_char(b'0');
+    ///
+    /// let ct_res = sks.pad_start(&ct, &width, &fill, 8);
+    /// assert_eq!(ct_res.len(), 8);
+    /// assert_eq!(cks.decrypt_ascii(&ct_res), "00042");
+    /// ```
+    pub fn pad_start(
+        &self,
+        str: &FheString,
+        width: &RadixCiphertext,
+        fill: &FheAsciiChar,
+        max_width: usize,
+    ) -> FheString {
+        if !str.is_padded() && str.len() >= max_width {
+            return str.clone();
+        }
+
+        let sks = self.integer_key();
+        let out_len = str.len().max(max_width);
+
+        if self.mode == ExecutionMode::Optimized && !str.is_padded() {
+            if let Ok(width) = width.decrypt_trivial::<u64>() {
+                let width = usize::try_from(width).map_or(max_width, |width| width.min(max_width));
+                let num_fill = width.saturating_sub(str.len());
+                let chars = std::iter::repeat(fill.clone())
+                    .take(num_fill)
+                    .chain(str.chars().iter().cloned())
+                    .chain(std::iter::repeat(self.create_trivial_char(0)))
+                    .take(out_len)
+                    .collect();
+                return FheString::new(chars, true);
+            }
+        }
+
+        // The number of fill characters is `max(min(width, max_width) - len, 0)`, `len` being
+        // the real length of the string
+        let len = if str.is_padded() {
+            let is_not_null = str
+                .chars()
+                .par_iter()
+                .map(|char| sks.scalar_ne_parallelized(&char.ciphertext, 0u8))
+                .collect();
+            self.count_true(is_not_null, out_len)
+        } else {
+            sks.create_trivial_radix(str.len() as u64, self.distance_num_blocks(out_len))
+        };
+        let num_fill = self.saturating_sub_to(width, &len, max_width);
+
+        let one_hot = self
+            .integer_key()
+            .one_hot_index_parallelized(&num_fill, out_len);
+
+        // The j-th character is a fill character if j < num_fill, and the (j - num_fill)-th
+        // character of the string otherwise
+        let mut reversed = str.chars().to_vec();
+        reversed.resize_with(out_len, || self.create_trivial_char(0));
+        reversed.reverse();
+
+        let chars = (0..out_len)
+            .into_par_iter()
+            .map(|j| {
+                let (char, is_fill) = rayon::join(
+                    || self.select_char(&reversed[out_len - 1 - j..], &one_hot),
+                    || sks.scalar_gt_parallelized(&num_fill, j as u64),
+                );
+                self.add_fill_if(char, fill, &is_fill)
+            })
+            .collect();
+
+        FheString::new(chars, true)
+    }
+
+    /// Pads the string on the right with the `fill` character up to `width` characters.
+    ///
+    /// The result has a fixed number of characters, see [Self::pad_start].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt_ascii("ab", Some(2));
+    /// let width = cks.as_ref().encrypt_radix(5u8, 2);
+    /// let fill = cks.encrypt_char(b'.');
+    ///
+    /// let ct_res = sks.pad_end(&ct, &width, &fill, 8);
+    /// assert_eq!(ct_res.len(), 8);
+    /// assert_eq!(cks.decrypt_ascii(&ct_res), "ab...");
+    /// ```
+    pub fn pad_end(
+        &self,
+        str: &FheString,
+        width: &RadixCiphertext,
+        fill: &FheAsciiChar,
+        max_width: usize,
+    ) -> FheString {
+        if !str.is_padded() && str.len() >= max_width {
+            return str.clone();
+        }
+
+        let sks = self.integer_key();
+        let out_len = str.len().max(max_width);
+        let width = sks.scalar_min_parallelized(width, max_width as u64);
+
+        // The j-th character is a fill character if it is past the content and
+        // j < min(width, max_width)
+        let chars = (0..out_len)
+            .into_par_iter()
+            .map(|j| match str.chars().get(j) {
+                Some(char) if !str.is_padded() => char.clone(),
+                Some(char) => {
+                    let (is_null, is_before_width) = rayon::join(
+                        || sks.scalar_eq_parallelized(&char.ciphertext, 0u8),
+                        || sks.scalar_gt_parallelized(&width, j as u64),
+                    );
+                    let is_fill = sks.boolean_bitand(&is_null, &is_before_width);
+                    self.add_fill_if(char.clone(), fill, &is_fill)
+                }
+                None => {
+                    let is_fill = sks.scalar_gt_parallelized(&width, j as u64);
+                    self.add_fill_if(self.create_trivial_char(0), fill, &is_fill)
+                }
+            })
+            .collect();
+
+        FheString::new(chars, true)
+    }
+
+    /// Returns `max(min(width, max_width) - len, 0)`.
+    fn saturating_sub_to(
+        &self,
+        width: &RadixCiphertext,
+        len: &RadixCiphertext,
+        max_width: usize,
+    ) -> RadixCiphertext {
+        let sks = self.integer_key();
+
+        let num_blocks = width.blocks().len().max(len.blocks().len());
+        let extend = |ct: &RadixCiphertext| {
+            sks.extend_radix_with_trivial_zero_blocks_msb(ct, num_blocks - ct.blocks().len())
+        };
+        let (width, len) = (extend(width), extend(len));
+
+        let width = sks.scalar_min_parallelized(&width, max_width as u64);
+        let (is_ge, mut diff) = rayon::join(
+            || sks.ge_parallelized(&width, &len),
+            || sks.sub_parallelized(&width, &len),
+        );
+        sks.zero_out_if_condition_is_false(&mut diff, is_ge.as_ref());
+        diff
+    }
+
+    /// Adds the `fill` character to `char` if `is_fill` is true, `char` being expected to be
+    /// null in this case.
+    fn add_fill_if(
+        &self,
+        char: FheAsciiChar,
+        fill: &FheAsciiChar,
+        is_fill: &BooleanBlock,
+    ) -> FheAsciiChar {
+        if self.mode == ExecutionMode::Optimized && matches!(is_fill.decrypt_trivial(), Ok(false)) {
+            return char;
+        }
+
+        let mut fill = fill.ciphertext.clone();
+        self.zero_out_if_false(&mut fill, is_fill);
+        FheAsciiChar::new(self.integer_key().add_parallelized(&char.ciphertext, &fill))
+    }
+}
@@ -0,0 +1,164 @@
+use super::ServerKey;
+use crate::integer::{BooleanBlock, RadixCiphertext};
+use crate::strings::ciphertext::FheString;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+impl<T> ServerKey<T>
+where
+    T: Borrow<crate::integer::ServerKey> + Sync,
+{
+    /// Returns whether the string starts with the `pattern`.
+    ///
+    /// Both strings may be padded. An empty pattern is a prefix of any string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt_ascii("tfhe-rs", None);
+    /// let pattern = cks.encrypt_ascii("tfhe", Some(2));
+    ///
+    /// let ct_res = sks.starts_with(&ct, &pattern);
+    /// assert!(cks.decrypt_bool(&ct_res));
+    /// ```
+    pub fn starts_with(&self, str: &FheString, pattern: &FheString) -> BooleanBlock {
+        if !pattern.is_padded() && pattern.len() > str.len() {
+            return self.integer_key().create_trivial_boolean_block(false);
+        }
+
+        let is_pattern_null = self.pattern_null_flags(pattern);
+        self.matches_at(str, pattern, is_pattern_null.as_deref(), 0)
+    }
+
+    /// Returns whether the string contains the `pattern`.
+    ///
+    /// Both strings may be padded. An empty pattern is contained in any string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt_ascii("tfhe-rs", Some(1));
+    /// let pattern = cks.encrypt_ascii("he", None);
+    ///
+    /// let ct_res = sks.contains(&ct, &pattern);
+    /// assert!(cks.decrypt_bool(&ct_res));
+    /// ```
+    pub fn contains(&self, str: &FheString, pattern: &FheString) -> BooleanBlock {
+        let matches = self.match_offsets(str, pattern);
+
+        self.any_true(matches)
+    }
+
+    /// Returns the index of the first occurrence of the `pattern` in the string, and whether
+    /// it was found.
+    ///
+    /// The index encrypts 0 if the pattern is not found.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt_ascii("abcabc", None);
+    /// let pattern = cks.encrypt_ascii("ca", Some(1));
+    ///
+    /// let (index, found) = sks.find(&ct, &pattern);
+    /// assert!(cks.decrypt_bool(&found));
+    /// let index: u8 = cks.as_ref().decrypt_radix(&index);
+    /// assert_eq!(index, 2);
+    /// ```
+    pub fn find(&self, str: &FheString, pattern: &FheString) -> (RadixCiphertext, BooleanBlock) {
+        let sks = self.integer_key();
+        let matches = self.match_offsets(str, pattern);
+
+        // The index is the number of offsets before the first match, where no match was found
+        let mut is_not_found_yet = Vec::with_capacity(matches.len());
+        let mut is_found = sks.create_trivial_boolean_block(false);
+        for is_match in matches {
+            is_found = sks.boolean_bitor(&is_found, &is_match);
+            is_not_found_yet.push(sks.boolean_bitnot(&is_found));
+        }
+
+        let mut index = self.count_true(is_not_found_yet, str.len() + 1);
+        self.zero_out_if_false(&mut index, &is_found);
+
+        (index, is_found)
+    }
+
+    /// Returns whether the pattern occurs at each of the offsets where it may start.
+    fn match_offsets(&self, str: &FheString, pattern: &FheString) -> Vec<BooleanBlock> {
+        // An unpadded pattern cannot start after str.len() - pattern.len()
+        let num_offsets = if pattern.is_padded() {
+            str.len() + 1
+        } else {
+            (str.len() + 1).saturating_sub(pattern.len())
+        };
+
+        let is_pattern_null = self.pattern_null_flags(pattern);
+
+        (0..num_offsets)
+            .into_par_iter()
+            .map(|offset| self.matches_at(str, pattern, is_pattern_null.as_deref(), offset))
+            .collect()
+    }
+
+    /// Returns whether each character of the pattern is null, or `None` if the pattern is not
+    /// padded.
+    fn pattern_null_flags(&self, pattern: &FheString) -> Option<Vec<BooleanBlock>> {
+        pattern.is_padded().then(|| {
+            pattern
+                .chars()
+                .par_iter()
+                .map(|char| {
+                    self.integer_key()
+                        .scalar_eq_parallelized(&char.ciphertext, 0u8)
+                })
+                .collect()
+        })
+    }
+
+    /// Returns whether the content of the pattern occurs at `offset` in the zero extended string.
+    ///
+    /// Each character of the pattern must be equal to the one of the string at the same
+    /// position, or be padding.
+    fn matches_at(
+        &self,
+        str: &FheString,
+        pattern: &FheString,
+        is_pattern_null: Option<&[BooleanBlock]>,
+        offset: usize,
+    ) -> BooleanBlock {
+        let sks = self.integer_key();
+
+        let flags = pattern
+            .chars()
+            .par_iter()
+            .enumerate()
+            .map(|(j, char)| {
+                let is_eq = str.chars().get(offset + j).map_or_else(
+                    || sks.scalar_eq_parallelized(&char.ciphertext, 0u8),
+                    |str_char| sks.eq_parallelized(&str_char.ciphertext, &char.ciphertext),
+                );
+                match is_pattern_null {
+                    Some(is_null) => sks.boolean_bitor(&is_eq, &is_null[j]),
+                    None => is_eq,
+                }
+            })
+            .collect();
+
+        self.all_true(flags)
+    }
+}
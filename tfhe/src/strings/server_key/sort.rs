@@ -0,0 +1,221 @@
+use super::{ExecutionMode, ServerKey};
+use crate::integer::{IntegerCiphertext, RadixCiphertext};
+use crate::strings::ciphertext::{FheAsciiChar, FheString};
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+/// Strings swapped together by the sorting network, the first one being the sorting key.
+type Row = Vec<FheString>;
+
+impl<T> ServerKey<T>
+where
+    T: Borrow<crate::integer::ServerKey> + Sync,
+{
+    /// Sorts the strings in lexicographic order.
+    ///
+    /// The strings go through a sorting network (Batcher's odd-even merge sort), whose sequence
+    /// of comparisons does not depend on the values. Each comparator encrypts the result of the
+    /// comparison and selects the swapped or unchanged pair obliviously, so that the permutation
+    /// is not revealed.
+    ///
+    /// All the strings are zero extended to the length of the longest one, the result strings
+    /// are padded if any input string is padded or if their lengths differ.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let strs = [
+    ///     cks.encrypt_ascii("bob", Some(1)),
+    ///     cks.encrypt_ascii("alice", None),
+    ///     cks.encrypt_ascii("bo", Some(2)),
+    /// ];
+    ///
+    /// let sorted = sks.sort(&strs);
+    /// let sorted: Vec<String> = sorted.iter().map(|str| cks.decrypt_ascii(str)).collect();
+    /// assert_eq!(sorted, ["alice", "bo", "bob"]);
+    /// ```
+    pub fn sort(&self, strs: &[FheString]) -> Vec<FheString> {
+        let rows = strs.iter().map(|str| vec![str.clone()]).collect();
+
+        self.sort_rows(rows)
+            .into_iter()
+            .map(|mut row| row.swap_remove(0))
+            .collect()
+    }
+
+    /// Sorts the strings in the lexicographic order of the keys computed by `f`.
+    ///
+    /// The key of each string is computed once, and is swapped along with it in the sorting
+    /// network (see [Self::sort]). The sort is not stable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let strs = [cks.encrypt_ascii("Ma", None), cks.encrypt_ascii("M", None)];
+    ///
+    /// // "4d61" > "4d"
+    /// let sorted = sks.sort_by_key(&strs, |str| sks.hex_encode(str));
+    /// let sorted: Vec<String> = sorted.iter().map(|str| cks.decrypt_ascii(str)).collect();
+    /// assert_eq!(sorted, ["M", "Ma"]);
+    /// ```
+    pub fn sort_by_key<F>(&self, strs: &[FheString], f: F) -> Vec<FheString>
+    where
+        F: Fn(&FheString) -> FheString + Sync,
+    {
+        let keys = strs.par_iter().map(&f).collect();
+
+        self.sort_with_keys(keys, strs)
+    }
+
+    /// Sorts the strings in the lexicographic order of the given keys, the key of `strs[i]`
+    /// being `keys[i]`.
+    pub(crate) fn sort_with_keys(
+        &self,
+        keys: Vec<FheString>,
+        strs: &[FheString],
+    ) -> Vec<FheString> {
+        assert_eq!(keys.len(), strs.len(), "There must be one key per string");
+
+        let rows = keys
+            .into_iter()
+            .zip(strs)
+            .map(|(key, str)| vec![key, str.clone()])
+            .collect();
+
+        self.sort_rows(rows)
+            .into_iter()
+            .map(|mut row| row.swap_remove(1))
+            .collect()
+    }
+
+    /// Sorts the rows by their first string with Batcher's odd-even merge sort.
+    ///
+    /// The network is the one for the next power of two, without the comparators involving
+    /// indices out of the rows. This amounts to sorting the rows followed by maximal elements,
+    /// which the comparators never move.
+    fn sort_rows(&self, rows: Vec<Row>) -> Vec<Row> {
+        let Some(num_columns) = rows.first().map(Vec::len) else {
+            return rows;
+        };
+
+        // The strings of a column need the same length to be swapped
+        let columns_info: Vec<_> = (0..num_columns)
+            .map(|column| {
+                let lengths = rows.iter().map(|row| row[column].len());
+                let max_len = lengths.clone().max().unwrap_or(0);
+                let padded = rows.iter().any(|row| row[column].is_padded())
+                    || lengths.clone().any(|len| len != max_len);
+                (max_len, padded)
+            })
+            .collect();
+
+        let mut rows: Vec<Row> = rows
+            .into_par_iter()
+            .map(|row| {
+                row.into_iter()
+                    .zip(&columns_info)
+                    .map(|(str, &(len, padded))| self.zero_extend(str, len, padded))
+                    .collect()
+            })
+            .collect();
+
+        let n = rows.len();
+        let mut p = 1;
+        while p < n {
+            let mut k = p;
+            while k > 0 {
+                let comparators: Vec<_> = (k % p..n.saturating_sub(k))
+                    .step_by(2 * k)
+                    .flat_map(|j| (0..k).map(move |i| (i + j, i + j + k)))
+                    .filter(|&(lhs, rhs)| rhs < n && lhs / (2 * p) == rhs / (2 * p))
+                    .collect();
+
+                // The comparators of a layer are on distinct indices
+                let swapped: Vec<_> = comparators
+                    .par_iter()
+                    .map(|&(lhs, rhs)| self.compare_and_swap(&rows[lhs], &rows[rhs]))
+                    .collect();
+
+                for ((lhs, rhs), (min, max)) in comparators.into_iter().zip(swapped) {
+                    rows[lhs] = min;
+                    rows[rhs] = max;
+                }
+
+                k /= 2;
+            }
+            p *= 2;
+        }
+
+        rows
+    }
+
+    /// Returns the lower and greater of the two rows, according to their first string.
+    fn compare_and_swap(&self, lhs: &Row, rhs: &Row) -> (Row, Row) {
+        let sks = self.integer_key();
+
+        let is_gt = self.gt(&lhs[0], &rhs[0]);
+        if self.mode == ExecutionMode::Optimized {
+            match is_gt.decrypt_trivial() {
+                Ok(true) => return (rhs.clone(), lhs.clone()),
+                Ok(false) => return (lhs.clone(), rhs.clone()),
+                Err(_) => {}
+            }
+        }
+
+        let (lhs_radix, rhs_radix) = (Self::row_to_radix(lhs), Self::row_to_radix(rhs));
+        let (min, max) = rayon::join(
+            || sks.if_then_else_parallelized(&is_gt, &rhs_radix, &lhs_radix),
+            || sks.if_then_else_parallelized(&is_gt, &lhs_radix, &rhs_radix),
+        );
+
+        (self.radix_to_row(&min, lhs), self.radix_to_row(&max, lhs))
+    }
+
+    /// Concatenates the blocks of all the characters of the row.
+    fn row_to_radix(row: &Row) -> RadixCiphertext {
+        let blocks = row
+            .iter()
+            .flat_map(FheString::chars)
+            .flat_map(|char| char.ciphertext.blocks())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from_blocks(blocks)
+    }
+
+    /// Splits a radix built by [Self::row_to_radix] into a row shaped like `shape`.
+    fn radix_to_row(&self, radix: &RadixCiphertext, shape: &Row) -> Row {
+        let num_blocks = self.num_blocks_per_char();
+        let mut blocks = radix.blocks().chunks_exact(num_blocks);
+
+        shape
+            .iter()
+            .map(|str| {
+                let chars = blocks
+                    .by_ref()
+                    .take(str.len())
+                    .map(|char| FheAsciiChar::new(RadixCiphertext::from_blocks(char.to_vec())))
+                    .collect();
+                FheString::new(chars, str.is_padded())
+            })
+            .collect()
+    }
+
+    /// Appends null characters to the string up to `len` characters.
+    fn zero_extend(&self, str: FheString, len: usize, padded: bool) -> FheString {
+        let mut chars = str.into_chars();
+        chars.resize_with(len, || self.create_trivial_char(0));
+
+        FheString::new(chars, padded)
+    }
+}
@@ -0,0 +1,139 @@
+use super::{ExecutionMode, ServerKey};
+use crate::integer::{BooleanBlock, RadixCiphertext};
+use crate::strings::ciphertext::{FheAsciiChar, FheString};
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+impl<T> ServerKey<T>
+where
+    T: Borrow<crate::integer::ServerKey> + Sync,
+{
+    /// Returns the character at the encrypted `index` of the string.
+    ///
+    /// The character is selected obliviously: all the characters of the string are read, and a
+    /// null character is returned if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt_ascii("tfhe", Some(2));
+    /// let index = cks.as_ref().encrypt_radix(2u8, 2);
+    ///
+    /// let ct_res = sks.char_at(&ct, &index);
+    /// assert_eq!(cks.decrypt_char(&ct_res), b'h');
+    /// ```
+    pub fn char_at(&self, str: &FheString, index: &RadixCiphertext) -> FheAsciiChar {
+        if self.mode == ExecutionMode::Optimized {
+            if let Ok(index) = index.decrypt_trivial::<u64>() {
+                return usize::try_from(index)
+                    .ok()
+                    .and_then(|index| str.chars().get(index))
+                    .map_or_else(|| self.create_trivial_char(0), Clone::clone);
+            }
+        }
+
+        let one_hot = self
+            .integer_key()
+            .one_hot_index_parallelized(index, str.len());
+        self.select_char(str.chars(), &one_hot)
+    }
+
+    /// Returns the characters of the string from the encrypted `start` index (included) to the
+    /// encrypted `end` index (excluded).
+    ///
+    /// As the indices are encrypted, the result has as many characters as the input string, the
+    /// ones that are not part of the substring being encrypted null characters. It is empty if
+    /// `start >= end`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt_ascii("tfhe-rs", None);
+    /// let start = cks.as_ref().encrypt_radix(1u8, 2);
+    /// let end = cks.as_ref().encrypt_radix(4u8, 2);
+    ///
+    /// let ct_res = sks.substring(&ct, &start, &end);
+    /// assert_eq!(ct_res.len(), 7);
+    /// assert_eq!(cks.decrypt_ascii(&ct_res), "fhe");
+    /// ```
+    pub fn substring(
+        &self,
+        str: &FheString,
+        start: &RadixCiphertext,
+        end: &RadixCiphertext,
+    ) -> FheString {
+        if self.mode == ExecutionMode::Optimized {
+            if let (Ok(start), Ok(end)) =
+                (start.decrypt_trivial::<u64>(), end.decrypt_trivial::<u64>())
+            {
+                let len = str.len() as u64;
+                let (start, end) = (start.min(len) as usize, end.min(len) as usize);
+                let chars = str.chars().get(start..end).unwrap_or_default().to_vec();
+                return FheString::new(chars, str.is_padded());
+            }
+        }
+
+        let sks = self.integer_key();
+        let len = str.len();
+
+        let (one_hot, chars) = rayon::join(
+            || self.integer_key().one_hot_index_parallelized(start, len),
+            || {
+                str.chars()
+                    .par_iter()
+                    .enumerate()
+                    .map(|(i, char)| {
+                        let is_before_end = sks.scalar_gt_parallelized(end, i as u64);
+                        let mut ct = char.ciphertext.clone();
+                        sks.zero_out_if_condition_is_false(&mut ct, is_before_end.as_ref());
+                        FheAsciiChar::new(ct)
+                    })
+                    .collect::<Vec<_>>()
+            },
+        );
+
+        // The j-th character of the result is the (start + j)-th character of the string
+        let chars = (0..len)
+            .into_par_iter()
+            .map(|j| self.select_char(&chars[j..], &one_hot))
+            .collect();
+
+        FheString::new(chars, true)
+    }
+
+    /// Returns the character selected by the `one_hot` mask, or a null character if none is
+    /// selected.
+    ///
+    /// Only the first `min(chars.len(), one_hot.len())` characters can be selected.
+    pub(super) fn select_char(
+        &self,
+        chars: &[FheAsciiChar],
+        one_hot: &[BooleanBlock],
+    ) -> FheAsciiChar {
+        let sks = self.integer_key();
+
+        let selected = chars
+            .par_iter()
+            .zip(one_hot.par_iter())
+            .map(|(char, is_selected)| {
+                let mut ct = char.ciphertext.clone();
+                sks.zero_out_if_condition_is_false(&mut ct, is_selected.as_ref());
+                ct
+            })
+            .collect::<Vec<_>>();
+
+        // As at most one character is not zeroed, the sum cannot overflow
+        sks.sum_ciphertexts_parallelized(&selected)
+            .map_or_else(|| self.create_trivial_char(0), FheAsciiChar::new)
+    }
+}
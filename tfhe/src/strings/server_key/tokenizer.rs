@@ -0,0 +1,217 @@
+use super::ServerKey;
+use crate::integer::{BooleanBlock, IntegerCiphertext, RadixCiphertext};
+use crate::strings::ciphertext::FheString;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+/// Characters separating the tokens: null, ASCII whitespace and ASCII punctuation.
+const SEPARATORS: [RangeInclusive<u8>; 7] = [
+    0..=0,
+    b'\t'..=b'\n',
+    b'\x0C'..=b'\r',
+    b' '..=b'/',
+    b':'..=b'@',
+    b'['..=b'`',
+    b'{'..=b'~',
+];
+
+/// Returns whether the clear byte separates tokens, see [SEPARATORS].
+fn is_clear_separator(byte: u8) -> bool {
+    byte == 0 || byte.is_ascii_whitespace() || byte.is_ascii_punctuation()
+}
+
+impl<T> ServerKey<T>
+where
+    T: Borrow<crate::integer::ServerKey> + Sync,
+{
+    /// Splits the string into tokens and maps them to their index in the clear `vocabulary`.
+    ///
+    /// Tokens are the maximal sequences of characters that are neither ASCII whitespace nor
+    /// ASCII punctuation. The ID of a token is the index of the matching word in the vocabulary,
+    /// or `vocabulary.len()` for tokens out of the vocabulary. Words are expected to be distinct,
+    /// the ones containing separators can never match.
+    ///
+    /// As the number of tokens is not known, the result has `max_tokens` slots, each one
+    /// holding an encrypted ID and whether the slot holds a token. The IDs of empty slots are 0,
+    /// and tokens after the first `max_tokens` ones are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::strings::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt_ascii("hi, bob", Some(1));
+    /// let vocabulary = ["bob", "hi"];
+    ///
+    /// let tokens = sks.tokenize(&ct, &vocabulary, 3);
+    /// let tokens: Vec<(u8, bool)> = tokens
+    ///     .iter()
+    ///     .map(|(id, is_present)| (cks.as_ref().decrypt_radix(id), cks.decrypt_bool(is_present)))
+    ///     .collect();
+    /// assert_eq!(tokens, [(1, true), (0, true), (0, false)]);
+    /// ```
+    pub fn tokenize(
+        &self,
+        str: &FheString,
+        vocabulary: &[&str],
+        max_tokens: usize,
+    ) -> Vec<(RadixCiphertext, BooleanBlock)> {
+        let sks = self.integer_key();
+        let len = str.len();
+        let num_blocks = self.distance_num_blocks(vocabulary.len());
+
+        // Null characters are separators, so the padding never belongs to a token
+        let is_separator = str
+            .chars()
+            .par_iter()
+            .map(|char| {
+                let flags = SEPARATORS
+                    .iter()
+                    .map(|range| self.is_in_range(&char.ciphertext, range.clone()))
+                    .collect();
+                self.any_true(flags)
+            })
+            .collect::<Vec<_>>();
+
+        // A token starts at i if the i-th character is not a separator, and the previous one is
+        let is_start = (0..len)
+            .into_par_iter()
+            .map(|i| {
+                let is_not_separator = sks.boolean_bitnot(&is_separator[i]);
+                match i.checked_sub(1) {
+                    Some(previous) => {
+                        sks.boolean_bitand(&is_not_separator, &is_separator[previous])
+                    }
+                    None => is_not_separator,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let token_ids = self.token_ids(str, vocabulary, &is_start, &is_separator, num_blocks);
+
+        // starts_before[i] is the number of tokens starting before the i-th character, so the
+        // token starting at i is in the slot starts_before[i]
+        let mut starts_before: Vec<RadixCiphertext> = Vec::with_capacity(len + 1);
+        starts_before.push(sks.create_trivial_radix(0u8, self.distance_num_blocks(len)));
+        for is_start in &is_start {
+            let count = starts_before.last().unwrap();
+            let is_start = is_start.clone().into_radix(count.blocks().len(), sks);
+            starts_before.push(sks.add_parallelized(count, &is_start));
+        }
+        let num_tokens = starts_before.pop().unwrap();
+
+        (0..max_tokens)
+            .into_par_iter()
+            .map(|slot| {
+                // The token in this slot starts at or after the character 2 * slot
+                let ids = (2 * slot..len)
+                    .into_par_iter()
+                    .map(|i| {
+                        let is_in_slot = sks.scalar_eq_parallelized(&starts_before[i], slot as u64);
+                        let is_selected = sks.boolean_bitand(&is_start[i], &is_in_slot);
+                        let mut id = token_ids[i].clone();
+                        self.zero_out_if_false(&mut id, &is_selected);
+                        id
+                    })
+                    .collect::<Vec<_>>();
+
+                // At most one of the IDs is not zeroed
+                let (id, is_present) = rayon::join(
+                    || {
+                        sks.sum_ciphertexts_parallelized(&ids)
+                            .unwrap_or_else(|| sks.create_trivial_radix(0u8, num_blocks))
+                    },
+                    || sks.scalar_gt_parallelized(&num_tokens, slot as u64),
+                );
+                (id, is_present)
+            })
+            .collect()
+    }
+
+    /// Returns, for each position of the string, the ID of the token starting there if any.
+    fn token_ids(
+        &self,
+        str: &FheString,
+        vocabulary: &[&str],
+        is_start: &[BooleanBlock],
+        is_separator: &[BooleanBlock],
+        num_blocks: usize,
+    ) -> Vec<RadixCiphertext> {
+        let sks = self.integer_key();
+        let len = str.len();
+
+        let words: Vec<_> = vocabulary
+            .iter()
+            .enumerate()
+            .filter(|(_, word)| !word.is_empty() && !word.bytes().any(is_clear_separator))
+            .collect();
+
+        // Compares each character of the string once to each character used in the vocabulary
+        let mut bytes: Vec<u8> = words.iter().flat_map(|(_, word)| word.bytes()).collect();
+        bytes.sort_unstable();
+        bytes.dedup();
+        let is_eq: BTreeMap<u8, Vec<BooleanBlock>> = bytes
+            .into_par_iter()
+            .map(|byte| {
+                let is_eq = str
+                    .chars()
+                    .par_iter()
+                    .map(|char| sks.scalar_eq_parallelized(&char.ciphertext, byte))
+                    .collect();
+                (byte, is_eq)
+            })
+            .collect();
+
+        (0..len)
+            .into_par_iter()
+            .map(|i| {
+                // The word matches if it starts a token here and is followed by a separator
+                let is_match = words
+                    .par_iter()
+                    .filter(|(_, word)| i + word.len() <= len)
+                    .map(|(index, word)| {
+                        let mut flags: Vec<_> = word
+                            .bytes()
+                            .enumerate()
+                            .map(|(j, byte)| is_eq[&byte][i + j].clone())
+                            .collect();
+                        flags.push(is_start[i].clone());
+                        if let Some(is_end) = is_separator.get(i + word.len()) {
+                            flags.push(is_end.clone());
+                        }
+                        (*index, self.all_true(flags))
+                    })
+                    .collect::<Vec<_>>();
+
+                let (mut ids, is_unknown) = rayon::join(
+                    || {
+                        is_match
+                            .par_iter()
+                            .map(|(index, is_match)| {
+                                let mut id = sks.create_trivial_radix(*index as u64, num_blocks);
+                                self.zero_out_if_false(&mut id, is_match);
+                                id
+                            })
+                            .collect::<Vec<_>>()
+                    },
+                    || {
+                        let flags = is_match.iter().map(|(_, flag)| flag.clone()).collect();
+                        sks.boolean_bitnot(&self.any_true(flags))
+                    },
+                );
+
+                let mut unknown_id = sks.create_trivial_radix(vocabulary.len() as u64, num_blocks);
+                self.zero_out_if_false(&mut unknown_id, &is_unknown);
+                ids.push(unknown_id);
+
+                // At most one of the IDs is not zeroed
+                sks.sum_ciphertexts_parallelized(&ids).unwrap()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,437 @@
+// This module contains the homomorphic bitwise operations and the adders used by the boolean
+// circuit version of SHA-256. Bits are encrypted as boolean blocks of the integer server key.
+// In the optimized execution mode, bits that are trivially known are folded so that they do not
+// cost any PBS.
+
+use super::Adder;
+use crate::integer::{BooleanBlock, IntegerCiphertext, RadixCiphertext, ServerKey};
+use crate::shortint::server_key::LookupTableOwned;
+use crate::shortint::Ciphertext;
+use crate::strings::ExecutionMode;
+use rayon::prelude::*;
+use std::array;
+
+/// A 32-bit word, its most significant bit first.
+pub(super) type Word = [BooleanBlock; 32];
+
+pub(super) struct BitOps<'a> {
+    sks: &'a ServerKey,
+    mode: ExecutionMode,
+    xor3_lut: LookupTableOwned,
+    maj_lut: LookupTableOwned,
+}
+
+impl<'a> BitOps<'a> {
+    pub(super) fn new(sks: &'a ServerKey, mode: ExecutionMode) -> Self {
+        // The sum of three bits fits in a block, so three inputs functions that only depend on
+        // the number of bits set are computed with a single PBS
+        let xor3_lut = sks.key.generate_lookup_table(|sum| sum & 1);
+        let maj_lut = sks.key.generate_lookup_table(|sum| u64::from(sum >= 2));
+
+        Self {
+            sks,
+            mode,
+            xor3_lut,
+            maj_lut,
+        }
+    }
+
+    pub(super) fn trivial(&self, value: bool) -> BooleanBlock {
+        self.sks.create_trivial_boolean_block(value)
+    }
+
+    pub(super) fn trivial_word(&self, value: u32) -> Word {
+        array::from_fn(|i| self.trivial((value >> (31 - i)) & 1 == 1))
+    }
+
+    /// Returns the value of a trivial bit, if it may be folded in the current execution mode.
+    fn known_value(&self, bit: &BooleanBlock) -> Option<bool> {
+        match self.mode {
+            ExecutionMode::ConstantTime => None,
+            ExecutionMode::Optimized => bit.decrypt_trivial().ok(),
+        }
+    }
+
+    pub(super) fn not(&self, a: &BooleanBlock) -> BooleanBlock {
+        self.sks.boolean_bitnot(a)
+    }
+
+    pub(super) fn and(&self, a: &BooleanBlock, b: &BooleanBlock) -> BooleanBlock {
+        match (self.known_value(a), self.known_value(b)) {
+            (Some(false), _) | (_, Some(false)) => self.trivial(false),
+            (Some(true), _) => b.clone(),
+            (_, Some(true)) => a.clone(),
+            _ => self.sks.boolean_bitand(a, b),
+        }
+    }
+
+    pub(super) fn or(&self, a: &BooleanBlock, b: &BooleanBlock) -> BooleanBlock {
+        match (self.known_value(a), self.known_value(b)) {
+            (Some(true), _) | (_, Some(true)) => self.trivial(true),
+            (Some(false), _) => b.clone(),
+            (_, Some(false)) => a.clone(),
+            _ => self.sks.boolean_bitor(a, b),
+        }
+    }
+
+    pub(super) fn xor(&self, a: &BooleanBlock, b: &BooleanBlock) -> BooleanBlock {
+        match (self.known_value(a), self.known_value(b)) {
+            (Some(false), _) => b.clone(),
+            (_, Some(false)) => a.clone(),
+            (Some(true), _) => self.not(b),
+            (_, Some(true)) => self.not(a),
+            _ => self.sks.boolean_bitxor(a, b),
+        }
+    }
+
+    fn sum3(&self, a: &BooleanBlock, b: &BooleanBlock, c: &BooleanBlock) -> Ciphertext {
+        let mut sum = a.0.clone();
+        self.sks.key.unchecked_add_assign(&mut sum, &b.0);
+        self.sks.key.unchecked_add_assign(&mut sum, &c.0);
+        sum
+    }
+
+    fn xor3(&self, a: &BooleanBlock, b: &BooleanBlock, c: &BooleanBlock) -> BooleanBlock {
+        let sum = self.sum3(a, b, c);
+        BooleanBlock::new_unchecked(self.sks.key.apply_lookup_table(&sum, &self.xor3_lut))
+    }
+
+    fn maj3(&self, a: &BooleanBlock, b: &BooleanBlock, c: &BooleanBlock) -> BooleanBlock {
+        let sum = self.sum3(a, b, c);
+        BooleanBlock::new_unchecked(self.sks.key.apply_lookup_table(&sum, &self.maj_lut))
+    }
+
+    /// Returns the `num_bits` least significant bits of a radix ciphertext, least significant
+    /// bit first.
+    pub(super) fn bits_of(&self, ct: &RadixCiphertext, num_bits: usize) -> Vec<BooleanBlock> {
+        let bits_per_block = self.sks.message_modulus().0.ilog2() as usize;
+        let luts = (0..bits_per_block)
+            .map(|t| self.sks.key.generate_lookup_table(|x| (x >> t) & 1))
+            .collect::<Vec<_>>();
+
+        (0..num_bits)
+            .into_par_iter()
+            .map(|k| {
+                ct.blocks().get(k / bits_per_block).map_or_else(
+                    || self.trivial(false),
+                    |block| {
+                        let bit = self
+                            .sks
+                            .key
+                            .apply_lookup_table(block, &luts[k % bits_per_block]);
+                        BooleanBlock::new_unchecked(bit)
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Packs the bits of a word in a radix ciphertext.
+    pub(super) fn word_to_radix(&self, word: &Word) -> RadixCiphertext {
+        let bits_per_block = self.sks.message_modulus().0.ilog2() as usize;
+        let num_blocks = (32 + bits_per_block - 1) / bits_per_block;
+
+        let blocks = (0..num_blocks)
+            .into_par_iter()
+            .map(|b| {
+                let mut block = self.sks.key.create_trivial(0);
+                for (t, k) in (b * bits_per_block..32).take(bits_per_block).enumerate() {
+                    let bit = self.sks.key.unchecked_scalar_mul(&word[31 - k].0, 1 << t);
+                    self.sks.key.unchecked_add_assign(&mut block, &bit);
+                }
+                self.sks.key.message_extract_assign(&mut block);
+                block
+            })
+            .collect();
+
+        RadixCiphertext::from_blocks(blocks)
+    }
+
+    pub(super) fn xor_words(&self, a: &Word, b: &Word) -> Word {
+        map_words(&[a, b], |bits| self.xor(bits[0], bits[1]))
+    }
+
+    fn and_words(&self, a: &Word, b: &Word) -> Word {
+        map_words(&[a, b], |bits| self.and(bits[0], bits[1]))
+    }
+
+    fn xor3_words(&self, a: &Word, b: &Word, c: &Word) -> Word {
+        map_words(&[a, b, c], |bits| self.xor3(bits[0], bits[1], bits[2]))
+    }
+
+    pub(super) fn maj(&self, a: &Word, b: &Word, c: &Word) -> Word {
+        map_words(&[a, b, c], |bits| self.maj3(bits[0], bits[1], bits[2]))
+    }
+
+    /// Chooses the bits of `y` where `x` is set, and the bits of `z` elsewhere.
+    pub(super) fn ch(&self, x: &Word, y: &Word, z: &Word) -> Word {
+        map_words(&[x, y, z], |bits| {
+            let (x, y, z) = (bits[0], bits[1], bits[2]);
+            self.xor(z, &self.and(x, &self.xor(y, z)))
+        })
+    }
+
+    /// Carry save adder: returns the sum and the carries of the three words, whose sum is the
+    /// sum of the three words modulo 2^32.
+    pub(super) fn csa(&self, a: &Word, b: &Word, c: &Word) -> (Word, Word) {
+        let (sum, carry) = rayon::join(|| self.xor3_words(a, b, c), || self.maj(a, b, c));
+
+        // Shift the carries left by one to discard the carry-out and set the carry-in to 0
+        let shifted_carry = array::from_fn(|i| {
+            carry
+                .get(i + 1)
+                .map_or_else(|| self.trivial(false), Clone::clone)
+        });
+
+        (sum, shifted_carry)
+    }
+
+    /// Adds two words modulo 2^32, the carries being computed with the given parallel prefix
+    /// algorithm.
+    pub(super) fn add(&self, a: &Word, b: &Word, adder: Adder) -> Word {
+        let (propagate, generate) = rayon::join(|| self.xor_words(a, b), || self.and_words(a, b));
+
+        let carry = match adder {
+            Adder::BrentKung => self.brent_kung(&propagate, &generate),
+            Adder::LadnerFischer => self.ladner_fischer(&propagate, &generate),
+        };
+
+        self.xor_words(&propagate, &carry)
+    }
+
+    /// Combines the generate signal at `index` with the one at `index + stride`.
+    fn grey_cell(
+        &self,
+        propagate: &Word,
+        generate: &Word,
+        index: usize,
+        stride: usize,
+    ) -> BooleanBlock {
+        self.or(
+            &generate[index],
+            &self.and(&generate[index + stride], &propagate[index]),
+        )
+    }
+
+    // Implementation of the Brent Kung parallel prefix algorithm
+    // This function computes the carry signals in parallel while minimizing the number of
+    // homomorphic operations
+    fn brent_kung(&self, propagate: &Word, generate: &Word) -> Word {
+        let mut propagate = propagate.clone();
+        let mut generate = generate.clone();
+
+        for d in 0..5 {
+            // first 5 stages
+            let stride = 1 << d;
+
+            let indices: Vec<(usize, usize)> = (0..32 - stride)
+                .rev()
+                .step_by(2 * stride)
+                .map(|i| i + 1 - stride)
+                .enumerate()
+                .collect();
+
+            let updates: Vec<(usize, BooleanBlock, BooleanBlock)> = indices
+                .into_par_iter()
+                .map(|(n, index)| {
+                    let new_g = self.grey_cell(&propagate, &generate, index, stride);
+                    let new_p = if n == 0 {
+                        // grey cell
+                        propagate[index].clone()
+                    } else {
+                        // black cell
+                        self.and(&propagate[index], &propagate[index + stride])
+                    };
+
+                    (index, new_p, new_g)
+                })
+                .collect();
+
+            for (index, new_p, new_g) in updates {
+                propagate[index] = new_p;
+                generate[index] = new_g;
+            }
+        }
+
+        let mut cells = 0;
+        for d in 0..4 {
+            // last 4 stages
+            let stride = 1 << (4 - d - 1);
+            cells += 1 << d;
+
+            let updates: Vec<(usize, BooleanBlock)> = (0..cells)
+                .into_par_iter()
+                .map(|cell| {
+                    let index = stride + 2 * stride * cell;
+                    (index, self.grey_cell(&propagate, &generate, index, stride))
+                })
+                .collect();
+
+            for (index, new_g) in updates {
+                generate[index] = new_g;
+            }
+        }
+
+        array::from_fn(|i| {
+            generate
+                .get(i + 1)
+                .map_or_else(|| self.trivial(false), Clone::clone)
+        })
+    }
+
+    // Implementation of the Ladner Fischer parallel prefix algorithm
+    // This function may perform better than the previous one when many threads are available as
+    // it has less stages
+    fn ladner_fischer(&self, propagate: &Word, generate: &Word) -> Word {
+        let mut propagate = propagate.clone();
+        let mut generate = generate.clone();
+
+        for d in 0..5 {
+            let stride = 1 << d;
+
+            let indices: Vec<(usize, usize)> = (0..32 - stride)
+                .rev()
+                .step_by(2 * stride)
+                .flat_map(|i| (0..stride).map(move |count| (i, count)))
+                .collect();
+
+            let updates: Vec<(usize, BooleanBlock, BooleanBlock)> = indices
+                .into_par_iter()
+                .map(|(i, count)| {
+                    let index = i - count; // current column
+
+                    // propagate and generate from a previous column
+                    let new_g = self.or(
+                        &generate[index],
+                        &self.and(&generate[i + 1], &propagate[index]),
+                    );
+                    let new_p = if index < 32 - (2 * stride) {
+                        // black cell
+                        self.and(&propagate[index], &propagate[i + 1])
+                    } else {
+                        // grey cell
+                        propagate[index].clone()
+                    };
+
+                    (index, new_p, new_g)
+                })
+                .collect();
+
+            for (index, new_p, new_g) in updates {
+                propagate[index] = new_p;
+                generate[index] = new_g;
+            }
+        }
+
+        array::from_fn(|i| {
+            generate
+                .get(i + 1)
+                .map_or_else(|| self.trivial(false), Clone::clone)
+        })
+    }
+
+    pub(super) fn sigma0(&self, x: &Word) -> Word {
+        self.xor3_words(
+            &rotate_right(x, 7),
+            &rotate_right(x, 18),
+            &self.shift_right(x, 3),
+        )
+    }
+
+    pub(super) fn sigma1(&self, x: &Word) -> Word {
+        self.xor3_words(
+            &rotate_right(x, 17),
+            &rotate_right(x, 19),
+            &self.shift_right(x, 10),
+        )
+    }
+
+    pub(super) fn sigma_upper_case_0(&self, x: &Word) -> Word {
+        self.xor3_words(
+            &rotate_right(x, 2),
+            &rotate_right(x, 13),
+            &rotate_right(x, 22),
+        )
+    }
+
+    pub(super) fn sigma_upper_case_1(&self, x: &Word) -> Word {
+        self.xor3_words(
+            &rotate_right(x, 6),
+            &rotate_right(x, 11),
+            &rotate_right(x, 25),
+        )
+    }
+
+    fn shift_right(&self, x: &Word, n: usize) -> Word {
+        let mut result = rotate_right(x, n);
+        result[..n].fill_with(|| self.trivial(false));
+        result
+    }
+}
+
+fn map_words<F>(words: &[&Word], f: F) -> Word
+where
+    F: Fn(&[&BooleanBlock]) -> BooleanBlock + Sync,
+{
+    let bits = (0..32)
+        .into_par_iter()
+        .map(|i| f(&words.iter().map(|word| &word[i]).collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+    bits.try_into().unwrap()
+}
+
+fn rotate_right(x: &Word, n: usize) -> Word {
+    let mut result = x.clone();
+    result.rotate_right(n);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::integer::IntegerKeyKind;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+    #[test]
+    fn test_add_modulo_2_32() {
+        let (cks, sks) =
+            KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+        let ops = BitOps::new(&sks, ExecutionMode::ConstantTime);
+
+        let encrypt = |value: u32| -> Word {
+            array::from_fn(|i| cks.encrypt_bool((value >> (31 - i)) & 1 == 1))
+        };
+        let decrypt = |word: &Word| {
+            word.iter().fold(0u32, |acc, bit| {
+                (acc << 1) | u32::from(cks.decrypt_bool(bit))
+            })
+        };
+
+        let (a, b, c) = (0x5be0_cd19u32, 0x3587_272bu32, 0xf0e1_d2c3u32);
+        let (ct_a, ct_b, ct_c) = (encrypt(a), encrypt(b), encrypt(c));
+
+        let (sum, carry) = ops.csa(&ct_a, &ct_b, &ct_c);
+        for adder in [Adder::BrentKung, Adder::LadnerFischer] {
+            let ct_res = ops.add(&sum, &carry, adder);
+            assert_eq!(
+                decrypt(&ct_res),
+                a.wrapping_add(b).wrapping_add(c),
+                "Invalid sum with {adder:?}"
+            );
+        }
+
+        let ct_res = ops.sigma0(&ct_a);
+        assert_eq!(
+            decrypt(&ct_res),
+            a.rotate_right(7) ^ a.rotate_right(18) ^ (a >> 3)
+        );
+
+        let ct_res = ops.ch(&ct_a, &ct_b, &ct_c);
+        assert_eq!(decrypt(&ct_res), (a & b) ^ (!a & c));
+
+        let ct_res = ops.word_to_radix(&ct_a);
+        let res: u32 = cks.decrypt_radix(&ct_res);
+        assert_eq!(res, a);
+    }
+}
@@ -0,0 +1,362 @@
+//! Homomorphic SHA-256 of encrypted strings.
+//!
+//! The hash is computed with a boolean circuit: the bits of the message are extracted from the
+//! encrypted characters, and the compression function works on words of 32 encrypted bits, the
+//! additions modulo 2^32 being computed with a parallel prefix [Adder].
+//!
+//! [sha256_radix] computes the same digest on words encrypted as radix ciphertexts, using the
+//! rotations, bitwise operations and additions of the integer server key.
+//!
+//! The message is padded on the server side. When the string is itself padded with null
+//! characters, the padding is done without revealing the real length of the string: all the
+//! chunks that a string of this capacity could need are hashed, and the intermediate hash of the
+//! last chunk of the real message is selected obliviously.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+//! use tfhe::strings::gen_keys;
+//! use tfhe::strings::sha256::{sha256, Adder};
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+//!
+//! // Trivial encryptions keep this example fast, encrypted strings are hashed the same way
+//! let ct = sks.create_trivial_ascii("abc");
+//!
+//! let digest = sha256(&sks, &ct, Adder::BrentKung);
+//! let digest: Vec<u32> = digest
+//!     .iter()
+//!     .map(|word| cks.as_ref().decrypt_radix(word))
+//!     .collect();
+//! assert_eq!(digest[0], 0xba7816bf);
+//! assert_eq!(digest[7], 0xf20015ad);
+//! ```
+
+mod boolean_ops;
+mod radix;
+
+use self::boolean_ops::{BitOps, Word};
+use super::ciphertext::FheString;
+use super::ServerKey;
+use crate::integer::{BooleanBlock, RadixCiphertext};
+use rayon::prelude::*;
+use std::array;
+use std::borrow::Borrow;
+
+/// Parallel prefix algorithm used to compute the carries of the additions.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Adder {
+    /// Minimizes the number of homomorphic operations.
+    #[default]
+    BrentKung,
+    /// Has less stages than [Adder::BrentKung], so it may perform better when many threads are
+    /// available.
+    LadnerFischer,
+}
+
+const INITIAL_HASH: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// Computes the SHA-256 digest of an encrypted string.
+///
+/// The digest is returned as eight radix ciphertexts encrypting 32-bit words, in the order of
+/// the SHA-256 specification. In [ExecutionMode::Optimized](super::ExecutionMode::Optimized)
+/// mode, the bits of trivial characters are folded instead of being computed on.
+pub fn sha256<T>(sks: &ServerKey<T>, input: &FheString, adder: Adder) -> [RadixCiphertext; 8]
+where
+    T: Borrow<crate::integer::ServerKey> + Sync,
+{
+    let ops = BitOps::new(sks.integer_key(), sks.execution_mode());
+
+    let (chunks, is_last_chunk) = pad_input(sks, &ops, input);
+
+    let mut hash = INITIAL_HASH.map(|value| ops.trivial_word(value));
+    let mut digest = [0; 8].map(|value| ops.trivial_word(value));
+
+    for (chunk, is_last) in chunks.iter().zip(is_last_chunk.iter()) {
+        hash = compress(&ops, &hash, chunk, adder);
+
+        // Keep the hash of the chunk where the padded message ends, at most one of the chunks
+        // being the last one
+        digest = array::from_fn(|i| {
+            let bits = (0..32)
+                .into_par_iter()
+                .map(|j| ops.or(&digest[i][j], &ops.and(is_last, &hash[i][j])))
+                .collect::<Vec<_>>();
+            bits.try_into().unwrap()
+        });
+    }
+
+    let words = digest
+        .par_iter()
+        .map(|word| ops.word_to_radix(word))
+        .collect::<Vec<_>>();
+    words.try_into().unwrap()
+}
+
+/// Computes the SHA-256 digest of an encrypted string, the words of the message being encrypted
+/// as radix ciphertexts.
+///
+/// The digest is returned as eight radix ciphertexts encrypting 32-bit words, in the order of
+/// the SHA-256 specification.
+///
+/// # Panics
+///
+/// Panics if the number of bits of message of a block does not divide 8, as the blocks of the
+/// characters are directly used to build the words.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// use tfhe::strings::gen_keys;
+/// use tfhe::strings::sha256::sha256_radix;
+///
+/// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+///
+/// let ct = sks.create_trivial_ascii("abc");
+///
+/// let digest = sha256_radix(&sks, &ct);
+/// let first_word: u32 = cks.as_ref().decrypt_radix(&digest[0]);
+/// assert_eq!(first_word, 0xba7816bf);
+/// ```
+pub fn sha256_radix<T>(sks: &ServerKey<T>, input: &FheString) -> [RadixCiphertext; 8]
+where
+    T: Borrow<crate::integer::ServerKey> + Sync,
+{
+    radix::sha256(sks, input)
+}
+
+/// Where the padded message of a string ends.
+struct PaddingMarkers {
+    /// `is_end[i]` is whether the string ends right before its i-th byte, for i in 0..=len.
+    is_end: Vec<BooleanBlock>,
+    /// The encrypted length of the string, or `None` if it is not padded.
+    length: Option<RadixCiphertext>,
+    /// Whether each chunk is the last one of the padded message.
+    is_last_chunk: Vec<BooleanBlock>,
+}
+
+/// Computes where the padded message ends, without revealing the length of padded strings.
+fn padding_markers<T>(sks: &ServerKey<T>, input: &FheString) -> PaddingMarkers
+where
+    T: Borrow<crate::integer::ServerKey> + Sync,
+{
+    let integer_key = sks.integer_key();
+    let len = input.len();
+
+    // Enough chunks for the string, the 0x80 end marker and the 64-bit length
+    let num_chunks = (len + 8) / 64 + 1;
+
+    if !input.is_padded() {
+        let is_end = (0..=len)
+            .map(|i| integer_key.create_trivial_boolean_block(i == len))
+            .collect();
+        let is_last_chunk = (0..num_chunks)
+            .map(|k| integer_key.create_trivial_boolean_block(k == num_chunks - 1))
+            .collect();
+
+        return PaddingMarkers {
+            is_end,
+            length: None,
+            is_last_chunk,
+        };
+    }
+
+    let is_not_null = input
+        .chars()
+        .par_iter()
+        .map(|char| integer_key.scalar_ne_parallelized(&char.ciphertext, 0u8))
+        .collect::<Vec<_>>();
+
+    // The string ends right before its i-th byte if the previous one is not null and the
+    // current one is null
+    let is_end = (0..=len)
+        .into_par_iter()
+        .map(|i| match (i.checked_sub(1), is_not_null.get(i)) {
+            (None, None) => integer_key.create_trivial_boolean_block(true),
+            (None, Some(is_current_not_null)) => integer_key.boolean_bitnot(is_current_not_null),
+            (Some(previous), None) => is_not_null[previous].clone(),
+            (Some(previous), Some(is_current_not_null)) => {
+                let is_current_null = integer_key.boolean_bitnot(is_current_not_null);
+                integer_key.boolean_bitand(&is_not_null[previous], &is_current_null)
+            }
+        })
+        .collect();
+
+    let length = sks.count_true(is_not_null, len);
+
+    // The message ends in the k-th chunk if 64 * k - 8 <= length < 64 * k + 56
+    let is_last_chunk = (0..num_chunks)
+        .into_par_iter()
+        .map(|k| {
+            let is_after_start =
+                (k > 0).then(|| integer_key.scalar_ge_parallelized(&length, (64 * k - 8) as u64));
+            let is_before_end = (64 * k + 56 <= len)
+                .then(|| integer_key.scalar_lt_parallelized(&length, (64 * k + 56) as u64));
+
+            match (is_after_start, is_before_end) {
+                (Some(lhs), Some(rhs)) => integer_key.boolean_bitand(&lhs, &rhs),
+                (Some(condition), None) | (None, Some(condition)) => condition,
+                (None, None) => integer_key.create_trivial_boolean_block(true),
+            }
+        })
+        .collect();
+
+    PaddingMarkers {
+        is_end,
+        length: Some(length),
+        is_last_chunk,
+    }
+}
+
+/// Pads the string as specified by SHA-256.
+///
+/// Returns the bits of the padded message, most significant bit first, split in chunks of 512
+/// bits, along with whether each chunk is the last one of the padded message.
+fn pad_input<T>(
+    sks: &ServerKey<T>,
+    ops: &BitOps,
+    input: &FheString,
+) -> (Vec<Vec<BooleanBlock>>, Vec<BooleanBlock>)
+where
+    T: Borrow<crate::integer::ServerKey> + Sync,
+{
+    let markers = padding_markers(sks, input);
+    let num_chunks = markers.is_last_chunk.len();
+    let num_length_bits = (usize::BITS - input.len().leading_zeros()) as usize;
+
+    let length_bits = markers.length.map_or_else(
+        || {
+            (0..num_length_bits)
+                .map(|b| ops.trivial((input.len() >> b) & 1 == 1))
+                .collect()
+        },
+        |length| ops.bits_of(&length, num_length_bits),
+    );
+    let (is_end, is_last_chunk) = (markers.is_end, markers.is_last_chunk);
+
+    let char_bits = input
+        .chars()
+        .par_iter()
+        .map(|char| ops.bits_of(&char.ciphertext, 7))
+        .collect::<Vec<_>>();
+
+    let mut bits = Vec::with_capacity(num_chunks * 512);
+    for i in 0..num_chunks * 64 {
+        // As ASCII characters have a null most significant bit, the end marker can be set
+        // without knowing where the string ends
+        bits.push(
+            is_end
+                .get(i)
+                .map_or_else(|| ops.trivial(false), Clone::clone),
+        );
+        match char_bits.get(i) {
+            Some(char_bits) => bits.extend(char_bits.iter().rev().cloned()),
+            None => bits.extend((0..7).map(|_| ops.trivial(false))),
+        }
+    }
+
+    // The last 64 bits of the last chunk encrypt the length in bits, i.e. 8 * length
+    let length_field = (0..num_chunks)
+        .flat_map(|k| (0..length_bits.len()).map(move |b| (k, b)))
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|(k, b)| {
+            let index = 512 * (k + 1) - 1 - (b + 3);
+            let bit = ops.or(&bits[index], &ops.and(&is_last_chunk[k], &length_bits[b]));
+            (index, bit)
+        })
+        .collect::<Vec<_>>();
+    for (index, bit) in length_field {
+        bits[index] = bit;
+    }
+
+    let chunks = bits.chunks_exact(512).map(<[_]>::to_vec).collect();
+    (chunks, is_last_chunk)
+}
+
+/// Applies the SHA-256 compression function on a 512 bits chunk.
+fn compress(ops: &BitOps, hash: &[Word; 8], chunk: &[BooleanBlock], adder: Adder) -> [Word; 8] {
+    // Compute the 64 words of the message schedule
+    let mut w: Vec<Word> = chunk
+        .chunks_exact(32)
+        .map(|word| array::from_fn(|i| word[i].clone()))
+        .collect();
+
+    for i in (16..64).step_by(2) {
+        let schedule = |i: usize| {
+            let (s0, s1) = rayon::join(|| ops.sigma0(&w[i - 15]), || ops.sigma1(&w[i - 2]));
+
+            let (sum, carry) = ops.csa(&s0, &w[i - 7], &w[i - 16]);
+            let (sum, carry) = ops.csa(&s1, &sum, &carry);
+            ops.add(&sum, &carry, adder)
+        };
+
+        let (word_i, word_u) = rayon::join(|| schedule(i), || schedule(i + 1));
+        w.push(word_i);
+        w.push(word_u);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = hash.clone();
+
+    // Compression loop
+    for i in 0..64 {
+        let (temp1, temp2) = rayon::join(
+            || {
+                let ((sum, carry), s1) = rayon::join(
+                    || {
+                        let ((sum, carry), ch) = rayon::join(
+                            || ops.csa(&h, &w[i], &ops.trivial_word(K[i])),
+                            || ops.ch(&e, &f, &g),
+                        );
+                        ops.csa(&sum, &carry, &ch)
+                    },
+                    || ops.sigma_upper_case_1(&e),
+                );
+
+                let (sum, carry) = ops.csa(&sum, &carry, &s1);
+                ops.add(&sum, &carry, adder)
+            },
+            || {
+                let (s0, maj) = rayon::join(|| ops.sigma_upper_case_0(&a), || ops.maj(&a, &b, &c));
+                ops.add(&s0, &maj, adder)
+            },
+        );
+
+        let (temp_e, temp_a) = rayon::join(
+            || ops.add(&d, &temp1, adder),
+            || ops.add(&temp1, &temp2, adder),
+        );
+
+        h = g;
+        g = f;
+        f = e;
+        e = temp_e;
+        d = c;
+        c = b;
+        b = a;
+        a = temp_a;
+    }
+
+    let result = vec![a, b, c, d, e, f, g, h];
+    let words = hash
+        .par_iter()
+        .zip(result.par_iter())
+        .map(|(hash, word)| ops.add(hash, word, adder))
+        .collect::<Vec<_>>();
+    words.try_into().unwrap()
+}
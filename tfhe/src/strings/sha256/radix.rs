@@ -0,0 +1,413 @@
+//! SHA-256 on 32-bit radix ciphertexts.
+//!
+//! The words of the message are built by concatenating the blocks of the characters, and the
+//! compression function uses the radix operations of the integer server key.
+
+use super::{padding_markers, INITIAL_HASH, K};
+use crate::integer::{BooleanBlock, IntegerCiphertext, RadixCiphertext, ServerKey};
+use crate::strings::ciphertext::FheString;
+use crate::strings::{ExecutionMode, CHAR_BITS};
+use rayon::prelude::*;
+use std::array;
+
+const WORD_BITS: u32 = 32;
+
+/// Computes the SHA-256 digest of the string, see [super::sha256_radix].
+pub(super) fn sha256<T>(
+    sks: &crate::strings::ServerKey<T>,
+    input: &FheString,
+) -> [RadixCiphertext; 8]
+where
+    T: std::borrow::Borrow<ServerKey> + Sync,
+{
+    let integer_key = sks.integer_key();
+    let bits_per_block = integer_key.message_modulus().0.ilog2();
+    assert!(
+        CHAR_BITS % bits_per_block == 0,
+        "The blocks of the characters must be aligned with their bytes"
+    );
+    let num_blocks = (WORD_BITS / bits_per_block) as usize;
+
+    let (chunks, is_last_chunk) = pad_input(sks, input, num_blocks);
+
+    let mut hash = INITIAL_HASH.map(|value| integer_key.create_trivial_radix(value, num_blocks));
+    let mut digest: Option<[RadixCiphertext; 8]> = None;
+
+    for (chunk, is_last) in chunks.iter().zip(is_last_chunk.iter()) {
+        hash = compress(integer_key, &hash, chunk);
+
+        // At most one of the chunks is the last one, so the selected hashes can be combined
+        // with a bitwise or
+        if let Some(selected) = select_if(sks, &hash, is_last) {
+            digest = Some(match digest {
+                Some(digest) => {
+                    let words = digest
+                        .par_iter()
+                        .zip(selected.par_iter())
+                        .map(|(lhs, rhs)| integer_key.bitor_parallelized(lhs, rhs))
+                        .collect::<Vec<_>>();
+                    words.try_into().unwrap()
+                }
+                None => selected,
+            });
+        }
+    }
+
+    digest.unwrap_or_else(|| [0u32; 8].map(|_| integer_key.create_trivial_radix(0u32, num_blocks)))
+}
+
+/// Returns the words, if the encrypted `condition` is true, or zeros otherwise.
+///
+/// In [ExecutionMode::Optimized] mode, `None` is returned when the condition is trivially false.
+fn select_if<T>(
+    sks: &crate::strings::ServerKey<T>,
+    words: &[RadixCiphertext; 8],
+    condition: &BooleanBlock,
+) -> Option<[RadixCiphertext; 8]>
+where
+    T: std::borrow::Borrow<ServerKey> + Sync,
+{
+    let known_condition = match sks.execution_mode() {
+        ExecutionMode::ConstantTime => None,
+        ExecutionMode::Optimized => condition.decrypt_trivial().ok(),
+    };
+
+    match known_condition {
+        Some(false) => None,
+        Some(true) => Some(words.clone()),
+        None => Some(words.clone().map(|mut word| {
+            sks.zero_out_if_false(&mut word, condition);
+            word
+        })),
+    }
+}
+
+/// Pads the string as specified by SHA-256.
+///
+/// Returns the words of the padded message split in chunks of 16 words, along with whether
+/// each chunk is the last one of the padded message.
+fn pad_input<T>(
+    sks: &crate::strings::ServerKey<T>,
+    input: &FheString,
+    num_blocks: usize,
+) -> (Vec<Vec<RadixCiphertext>>, Vec<BooleanBlock>)
+where
+    T: std::borrow::Borrow<ServerKey> + Sync,
+{
+    let integer_key = sks.integer_key();
+    let shortint_key = &integer_key.key;
+    let markers = padding_markers(sks, input);
+
+    // Trivial markers are only skipped in the optimized mode
+    let is_optimized = sks.execution_mode() == ExecutionMode::Optimized;
+    let is_trivially_false =
+        |marker: &BooleanBlock| is_optimized && matches!(marker.decrypt_trivial(), Ok(false));
+    let num_chunks = markers.is_last_chunk.len();
+    let num_blocks_per_char = sks.num_blocks_per_char();
+
+    // The end marker 0x80 is the most significant bit of the last block of a character
+    let marker_value = shortint_key.message_modulus.0 as u8 / 2;
+
+    let bytes = (0..num_chunks * 64)
+        .into_par_iter()
+        .map(|i| {
+            let mut byte = input.chars().get(i).map_or_else(
+                || integer_key.create_trivial_radix(0u8, num_blocks_per_char),
+                |char| char.ciphertext.clone(),
+            );
+
+            let is_end = markers.is_end.get(i);
+            if let Some(is_end) = is_end.filter(|is_end| !is_trivially_false(is_end)) {
+                // As ASCII characters have a null most significant bit, and the string ends
+                // before a null character, the marker can be added without carry
+                let marker = shortint_key.unchecked_scalar_mul(is_end.as_ref(), marker_value);
+                let block = byte.blocks_mut().last_mut().unwrap();
+                shortint_key.unchecked_add_assign(block, &marker);
+                shortint_key.message_extract_assign(block);
+            }
+
+            byte
+        })
+        .collect::<Vec<_>>();
+
+    // Words are big endian, and the blocks of a radix ciphertext are little endian
+    let mut words = bytes
+        .chunks_exact(4)
+        .map(|word| {
+            let blocks = word
+                .iter()
+                .rev()
+                .flat_map(|byte| byte.blocks().iter().cloned())
+                .collect::<Vec<_>>();
+            RadixCiphertext::from(blocks)
+        })
+        .collect::<Vec<_>>();
+
+    // The last 64 bits of the last chunk encrypt the length in bits, i.e. 8 * length, which
+    // always fits in the last word
+    let length_in_bits = markers.length.map_or_else(
+        || integer_key.create_trivial_radix(8 * input.len() as u64, num_blocks),
+        |length| {
+            let length = integer_key.extend_radix_with_trivial_zero_blocks_msb(
+                &length,
+                num_blocks - length.blocks().len(),
+            );
+            integer_key.scalar_left_shift_parallelized(&length, 3u32)
+        },
+    );
+
+    let length_words = markers
+        .is_last_chunk
+        .par_iter()
+        .enumerate()
+        .filter(|(_, is_last)| !is_trivially_false(is_last))
+        .map(|(k, is_last)| {
+            let mut length = length_in_bits.clone();
+            if !is_optimized || is_last.decrypt_trivial().is_err() {
+                sks.zero_out_if_false(&mut length, is_last);
+            }
+            let word = integer_key.bitor_parallelized(&words[16 * k + 15], &length);
+            (16 * k + 15, word)
+        })
+        .collect::<Vec<_>>();
+    for (index, word) in length_words {
+        words[index] = word;
+    }
+
+    let chunks = words.chunks_exact(16).map(<[_]>::to_vec).collect();
+    (chunks, markers.is_last_chunk)
+}
+
+/// Applies the SHA-256 compression function on a chunk of 16 words.
+fn compress(
+    sks: &ServerKey,
+    hash: &[RadixCiphertext; 8],
+    chunk: &[RadixCiphertext],
+) -> [RadixCiphertext; 8] {
+    // Compute the 64 words of the message schedule
+    let mut w = chunk.to_vec();
+
+    for i in (16..64).step_by(2) {
+        let schedule = |i: usize| {
+            let (s0, s1) = rayon::join(|| sigma0(sks, &w[i - 15]), || sigma1(sks, &w[i - 2]));
+
+            let (lhs, rhs) = rayon::join(
+                || sks.add_parallelized(&w[i - 16], &s0),
+                || sks.add_parallelized(&w[i - 7], &s1),
+            );
+            sks.add_parallelized(&lhs, &rhs)
+        };
+
+        let (word_i, word_u) = rayon::join(|| schedule(i), || schedule(i + 1));
+        w.push(word_i);
+        w.push(word_u);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = hash.clone();
+
+    // Compression loop
+    for i in 0..64 {
+        let (temp1, temp2) = rayon::join(
+            || {
+                let ((s1, ch), k_plus_w) = rayon::join(
+                    || {
+                        rayon::join(
+                            || sigma_upper_case_1(sks, &e),
+                            || ch_function(sks, &e, &f, &g),
+                        )
+                    },
+                    || {
+                        let h_plus_w = sks.add_parallelized(&h, &w[i]);
+                        sks.scalar_add_parallelized(&h_plus_w, K[i])
+                    },
+                );
+
+                let s1_plus_ch = sks.add_parallelized(&s1, &ch);
+                sks.add_parallelized(&s1_plus_ch, &k_plus_w)
+            },
+            || {
+                let (s0, maj) = rayon::join(
+                    || sigma_upper_case_0(sks, &a),
+                    || maj_function(sks, &a, &b, &c),
+                );
+                sks.add_parallelized(&s0, &maj)
+            },
+        );
+
+        let (temp_e, temp_a) = rayon::join(
+            || sks.add_parallelized(&d, &temp1),
+            || sks.add_parallelized(&temp1, &temp2),
+        );
+
+        h = g;
+        g = f;
+        f = e;
+        e = temp_e;
+        d = c;
+        c = b;
+        b = a;
+        a = temp_a;
+    }
+
+    let result = [a, b, c, d, e, f, g, h];
+    array::from_fn(|i| sks.add_parallelized(&hash[i], &result[i]))
+}
+
+fn xor3(
+    sks: &ServerKey,
+    x: &RadixCiphertext,
+    y: &RadixCiphertext,
+    z: &RadixCiphertext,
+) -> RadixCiphertext {
+    sks.bitxor_parallelized(&sks.bitxor_parallelized(x, y), z)
+}
+
+fn rotate_right(sks: &ServerKey, x: &RadixCiphertext, n: u32) -> RadixCiphertext {
+    sks.scalar_rotate_right_parallelized(x, n)
+}
+
+fn sigma0(sks: &ServerKey, x: &RadixCiphertext) -> RadixCiphertext {
+    let ((a, b), c) = rayon::join(
+        || rayon::join(|| rotate_right(sks, x, 7), || rotate_right(sks, x, 18)),
+        || sks.scalar_right_shift_parallelized(x, 3u32),
+    );
+    xor3(sks, &a, &b, &c)
+}
+
+fn sigma1(sks: &ServerKey, x: &RadixCiphertext) -> RadixCiphertext {
+    let ((a, b), c) = rayon::join(
+        || rayon::join(|| rotate_right(sks, x, 17), || rotate_right(sks, x, 19)),
+        || sks.scalar_right_shift_parallelized(x, 10u32),
+    );
+    xor3(sks, &a, &b, &c)
+}
+
+fn sigma_upper_case_0(sks: &ServerKey, x: &RadixCiphertext) -> RadixCiphertext {
+    let ((a, b), c) = rayon::join(
+        || rayon::join(|| rotate_right(sks, x, 2), || rotate_right(sks, x, 13)),
+        || rotate_right(sks, x, 22),
+    );
+    xor3(sks, &a, &b, &c)
+}
+
+fn sigma_upper_case_1(sks: &ServerKey, x: &RadixCiphertext) -> RadixCiphertext {
+    let ((a, b), c) = rayon::join(
+        || rayon::join(|| rotate_right(sks, x, 6), || rotate_right(sks, x, 11)),
+        || rotate_right(sks, x, 25),
+    );
+    xor3(sks, &a, &b, &c)
+}
+
+/// Computes `(x & y) ^ (!x & z)` as `z ^ (x & (y ^ z))`.
+fn ch_function(
+    sks: &ServerKey,
+    x: &RadixCiphertext,
+    y: &RadixCiphertext,
+    z: &RadixCiphertext,
+) -> RadixCiphertext {
+    let y_xor_z = sks.bitxor_parallelized(y, z);
+    let selected = sks.bitand_parallelized(x, &y_xor_z);
+    sks.bitxor_parallelized(z, &selected)
+}
+
+/// Computes `(x & y) ^ (x & z) ^ (y & z)` as `(x & y) | (z & (x ^ y))`.
+fn maj_function(
+    sks: &ServerKey,
+    x: &RadixCiphertext,
+    y: &RadixCiphertext,
+    z: &RadixCiphertext,
+) -> RadixCiphertext {
+    let (x_and_y, x_xor_y) = rayon::join(
+        || sks.bitand_parallelized(x, y),
+        || sks.bitxor_parallelized(x, y),
+    );
+    let z_and_x_xor_y = sks.bitand_parallelized(z, &x_xor_y);
+    sks.bitor_parallelized(&x_and_y, &z_and_x_xor_y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    use crate::strings::{gen_keys, ServerKey};
+
+    #[test]
+    fn test_radix_words() {
+        let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let integer_key = sks.integer_key();
+
+        // The padding of an encrypted padded string hides its length
+        let ct = cks.encrypt_ascii("abc", Some(2));
+        let (chunks, is_last_chunk) = pad_input(&sks, &ct, 16);
+        assert_eq!(chunks.len(), 1);
+        assert!(cks.as_ref().decrypt_bool(&is_last_chunk[0]));
+
+        let words: Vec<u32> = chunks[0]
+            .iter()
+            .map(|word| cks.as_ref().decrypt_radix(word))
+            .collect();
+        let mut expected = [0; 16];
+        expected[0] = 0x6162_6380;
+        expected[15] = 24;
+        assert_eq!(words, expected);
+
+        let (a, b, c) = (0x5be0_cd19u32, 0x3587_272bu32, 0xf0e1_d2c3u32);
+        let [ct_a, ct_b, ct_c] = [a, b, c].map(|value| cks.as_ref().encrypt_radix(value, 16));
+        let decrypt = |ct: &RadixCiphertext| -> u32 { cks.as_ref().decrypt_radix(ct) };
+
+        let ct_res = sigma0(integer_key, &ct_a);
+        assert_eq!(
+            decrypt(&ct_res),
+            a.rotate_right(7) ^ a.rotate_right(18) ^ (a >> 3)
+        );
+
+        let ct_res = ch_function(integer_key, &ct_a, &ct_b, &ct_c);
+        assert_eq!(decrypt(&ct_res), (a & b) ^ (!a & c));
+
+        let ct_res = maj_function(integer_key, &ct_a, &ct_b, &ct_c);
+        assert_eq!(decrypt(&ct_res), (a & b) ^ (a & c) ^ (b & c));
+    }
+
+    #[test]
+    fn test_radix_sha256_encrypted_padded_string() {
+        let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+        // The padded string needs two chunks, the digest of the first one is selected with an
+        // encrypted condition
+        let ct = cks.encrypt_ascii("abc", Some(60));
+
+        let digest = sha256(&sks, &ct)
+            .iter()
+            .map(|word| format!("{:08x}", cks.as_ref().decrypt_radix::<u32>(word)))
+            .collect::<Vec<_>>()
+            .concat();
+        assert_eq!(
+            digest,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_radix_sha256_trivial_padded_string() {
+        let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+        // The markers of a trivial padded string are trivial, the constant time mode must still
+        // select the digest and write the length without skipping them
+        let mut chars = sks.create_trivial_ascii("abc").into_chars();
+        chars.extend((0..60).map(|_| sks.create_trivial_char(0)));
+        let ct = FheString::new(chars, true);
+
+        for mode in [ExecutionMode::ConstantTime, ExecutionMode::Optimized] {
+            let sks = ServerKey::new_with_mode(sks.integer_key(), mode);
+            let digest = sha256(&sks, &ct)
+                .iter()
+                .map(|word| format!("{:08x}", cks.as_ref().decrypt_radix::<u32>(word)))
+                .collect::<Vec<_>>()
+                .concat();
+            assert_eq!(
+                digest, "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+                "Invalid digest in {mode:?} mode"
+            );
+        }
+    }
+}
@@ -0,0 +1,638 @@
+use crate::integer::{BooleanBlock, RadixCiphertext};
+use crate::shortint::parameters::parameters_compact_pk::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+use crate::strings::sha256::{sha256, sha256_radix, Adder};
+use crate::strings::{
+    gen_keys, ClientKey, CompactPublicKey, CompressedPublicKey, ExecutionMode, FheString,
+    FheStringMap, PublicKey, ServerKey,
+};
+
+#[test]
+fn string_encrypt_decrypt() {
+    let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    for (str, padding) in [
+        ("", None),
+        ("", Some(2)),
+        ("tfhe", None),
+        ("tfhe-rs", Some(3)),
+    ] {
+        let ct = cks.encrypt_ascii(str, padding);
+        assert_eq!(ct.len(), str.len() + padding.unwrap_or(0) as usize);
+        assert_eq!(ct.is_padded(), padding.is_some_and(|p| p > 0));
+        assert_eq!(cks.decrypt_ascii(&ct), str);
+
+        let ct = cks.encrypt_ascii_compressed(str, padding).decompress();
+        assert_eq!(cks.decrypt_ascii(&ct), str);
+    }
+
+    let ct = sks.create_trivial_ascii("trivial");
+    assert_eq!(cks.decrypt_ascii(&ct), "trivial");
+}
+
+#[test]
+#[should_panic(expected = "non null ASCII characters")]
+fn string_encrypt_non_ascii() {
+    let (cks, _sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let _ = cks.encrypt_ascii("héllo", None);
+}
+
+#[test]
+fn string_public_key_encryption() {
+    let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let compressed_pks = CompressedPublicKey::new(&cks);
+
+    for (str, padding) in [("", Some(1)), ("fhe", None)] {
+        let ct = compressed_pks.encrypt_ascii(str, padding);
+        assert_eq!(ct.len(), str.len() + padding.unwrap_or(0) as usize);
+        assert_eq!(cks.decrypt_ascii(&ct), str);
+    }
+
+    let pks = compressed_pks.decompress();
+    let ct = pks.encrypt_ascii("ab", Some(1));
+    assert_eq!(ct.len(), 3);
+    assert!(ct.is_padded());
+    assert_eq!(cks.decrypt_ascii(&ct), "ab");
+
+    let integer_pks = crate::integer::PublicKey::new(&cks);
+    let pks = PublicKey::from(&integer_pks);
+    let ct = pks.encrypt_ascii("z", None);
+    assert_eq!(cks.decrypt_ascii(&ct), "z");
+}
+
+#[test]
+fn string_compact_public_key_encryption() {
+    let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    let pks = CompactPublicKey::new(&cks);
+
+    for (str, padding) in [
+        ("", None),
+        ("", Some(2)),
+        ("tfhe", None),
+        ("tfhe-rs", Some(3)),
+    ] {
+        let compact = pks.encrypt_ascii(str, padding);
+        assert_eq!(
+            compact.len(),
+            (str.len() + padding.unwrap_or(0) as usize).max(1)
+        );
+
+        let ct = compact.expand();
+        assert_eq!(ct.len(), compact.len());
+        assert_eq!(ct.is_padded(), compact.is_padded());
+        assert_eq!(cks.decrypt_ascii(&ct), str);
+    }
+}
+
+#[test]
+fn string_comparisons() {
+    let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let cases = [
+        (("ab", None), ("ab", None)),
+        (("ab", Some(2)), ("ab", None)),
+        (("ab", None), ("abc", Some(1))),
+        (("b", Some(1)), ("abc", None)),
+        (("", Some(1)), ("", None)),
+        (("zz", None), ("za", Some(2))),
+    ];
+
+    for ((lhs, lhs_padding), (rhs, rhs_padding)) in cases {
+        let ct_lhs = cks.encrypt_ascii(lhs, lhs_padding);
+        let ct_rhs = cks.encrypt_ascii(rhs, rhs_padding);
+
+        let results = [
+            (sks.eq(&ct_lhs, &ct_rhs), lhs == rhs, "=="),
+            (sks.ne(&ct_lhs, &ct_rhs), lhs != rhs, "!="),
+            (sks.lt(&ct_lhs, &ct_rhs), lhs < rhs, "<"),
+            (sks.le(&ct_lhs, &ct_rhs), lhs <= rhs, "<="),
+            (sks.gt(&ct_lhs, &ct_rhs), lhs > rhs, ">"),
+            (sks.ge(&ct_lhs, &ct_rhs), lhs >= rhs, ">="),
+        ];
+
+        for (ct_res, expected, op) in results {
+            assert_eq!(
+                cks.decrypt_bool(&ct_res),
+                expected,
+                "Invalid result for {lhs:?} {op} {rhs:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn string_comparisons_execution_modes() {
+    let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    // Encrypts the characters at odd positions, the other ones are trivial
+    let encrypt_mixed = |str: &str, padding: u32| {
+        let chars = str
+            .bytes()
+            .chain(std::iter::repeat(0).take(padding as usize))
+            .enumerate()
+            .map(|(i, byte)| {
+                if i % 2 == 1 {
+                    cks.encrypt_char(byte)
+                } else {
+                    sks.create_trivial_char(byte)
+                }
+            })
+            .collect();
+        FheString::new(chars, padding > 0)
+    };
+
+    let cases = [
+        (("abc", 0), ("abd", 0)),
+        (("abc", 2), ("abc", 0)),
+        (("ab", 1), ("abc", 0)),
+        (("b", 0), ("abc", 0)),
+        (("", 2), ("", 0)),
+    ];
+
+    for mode in [ExecutionMode::ConstantTime, ExecutionMode::Optimized] {
+        let sks = ServerKey::new_with_mode(sks.integer_key(), mode);
+        assert_eq!(sks.execution_mode(), mode);
+
+        for ((lhs, lhs_padding), (rhs, rhs_padding)) in cases {
+            let ct_lhs = encrypt_mixed(lhs, lhs_padding);
+            let ct_rhs = encrypt_mixed(rhs, rhs_padding);
+
+            let results = [
+                (sks.eq(&ct_lhs, &ct_rhs), lhs == rhs, "=="),
+                (sks.ne(&ct_lhs, &ct_rhs), lhs != rhs, "!="),
+                (sks.lt(&ct_lhs, &ct_rhs), lhs < rhs, "<"),
+                (sks.ge(&ct_lhs, &ct_rhs), lhs >= rhs, ">="),
+            ];
+
+            for (ct_res, expected, op) in results {
+                assert_eq!(
+                    cks.decrypt_bool(&ct_res),
+                    expected,
+                    "Invalid result for {lhs:?} {op} {rhs:?} in {mode:?} mode"
+                );
+            }
+        }
+    }
+
+    // In optimized mode, the first difference being trivially known, no computation is needed
+    let mut optimized_sks = ServerKey::new(sks.integer_key());
+    optimized_sks.set_execution_mode(ExecutionMode::Optimized);
+    let ct_lhs = sks.create_trivial_ascii("ba");
+    let ct_rhs = encrypt_mixed("abc", 0);
+    let ct_res = optimized_sks.gt(&ct_lhs, &ct_rhs);
+    assert_eq!(ct_res.decrypt_trivial(), Ok(true));
+}
+
+#[test]
+fn string_char_at_and_substring() {
+    let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let str = "fhe";
+    let ct = cks.encrypt_ascii(str, Some(1));
+    let encrypt_index = |index: u8| cks.as_ref().encrypt_radix(index, 2);
+
+    for index in [0u8, 2, 3, 7] {
+        let ct_res = sks.char_at(&ct, &encrypt_index(index));
+        let expected = str.as_bytes().get(index as usize).copied().unwrap_or(0);
+        assert_eq!(
+            cks.decrypt_char(&ct_res),
+            expected,
+            "Invalid char at {index}"
+        );
+    }
+
+    for (start, end) in [(0u8, 3u8), (1, 2), (1, 9), (2, 1)] {
+        let ct_res = sks.substring(&ct, &encrypt_index(start), &encrypt_index(end));
+        assert_eq!(ct_res.len(), ct.len());
+        assert!(ct_res.is_padded());
+
+        let expected = str
+            .get(start as usize..(end as usize).min(str.len()))
+            .unwrap_or_default();
+        assert_eq!(
+            cks.decrypt_ascii(&ct_res),
+            expected,
+            "Invalid substring {start}..{end}"
+        );
+    }
+
+    // In optimized mode, trivial indices make the selection free
+    let sks = ServerKey::new_with_mode(sks.integer_key(), ExecutionMode::Optimized);
+    let trivial_index = |index: u8| sks.integer_key().create_trivial_radix(index, 2);
+
+    let ct_res = sks.char_at(&ct, &trivial_index(1));
+    assert_eq!(cks.decrypt_char(&ct_res), b'h');
+
+    let ct_res = sks.substring(&ct, &trivial_index(1), &trivial_index(3));
+    assert_eq!(ct_res.len(), 2);
+    assert_eq!(cks.decrypt_ascii(&ct_res), "he");
+}
+
+#[test]
+fn string_distances() {
+    let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let decrypt = |ct: &crate::integer::RadixCiphertext| -> u64 { cks.as_ref().decrypt_radix(ct) };
+
+    let cases = [
+        (("kit", None), ("sit", Some(1)), 1, 1),
+        (("ab", Some(1)), ("ba", None), 2, 2),
+        (("", Some(1)), ("ab", None), 2, 2),
+        (("abc", None), ("b", Some(1)), 3, 2),
+    ];
+
+    for ((lhs, lhs_padding), (rhs, rhs_padding), hamming, levenshtein) in cases {
+        let ct_lhs = cks.encrypt_ascii(lhs, lhs_padding);
+        let ct_rhs = cks.encrypt_ascii(rhs, rhs_padding);
+
+        let ct_res = sks.hamming(&ct_lhs, &ct_rhs);
+        assert_eq!(
+            decrypt(&ct_res),
+            hamming,
+            "Invalid hamming({lhs:?}, {rhs:?})"
+        );
+        let ct_res = sks.scalar_hamming(&ct_lhs, rhs);
+        assert_eq!(
+            decrypt(&ct_res),
+            hamming,
+            "Invalid hamming({lhs:?}, {rhs:?})"
+        );
+
+        let ct_res = sks.levenshtein(&ct_lhs, &ct_rhs);
+        assert_eq!(
+            decrypt(&ct_res),
+            levenshtein,
+            "Invalid levenshtein({lhs:?}, {rhs:?})"
+        );
+        let ct_res = sks.scalar_levenshtein(&ct_lhs, rhs);
+        assert_eq!(
+            decrypt(&ct_res),
+            levenshtein,
+            "Invalid levenshtein({lhs:?}, {rhs:?})"
+        );
+    }
+}
+
+#[test]
+fn string_sha256() {
+    let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let long_str = "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnomnopnopq";
+    let cases = [
+        (
+            "",
+            0,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        (
+            "abc",
+            0,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        ),
+        // The padded string needs two chunks, the message ends in the first one
+        (
+            "abc",
+            60,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        ),
+        // The message ends in the second chunk
+        (
+            long_str,
+            2,
+            "fe55bfea2d416f585af361745d83334abbafa3922ea8436494c06e00048711ce",
+        ),
+    ];
+
+    // Trivial strings (padding included) keep this test fast, the hash of encrypted strings is
+    // tested in string_sha256_encrypted
+    for (str, padding, expected) in cases {
+        let mut chars = sks.create_trivial_ascii(str).into_chars();
+        chars.extend((0..padding).map(|_| sks.create_trivial_char(0)));
+        let ct = FheString::new(chars, padding > 0);
+
+        for mode in [ExecutionMode::ConstantTime, ExecutionMode::Optimized] {
+            let sks = ServerKey::new_with_mode(sks.integer_key(), mode);
+
+            for adder in [Adder::BrentKung, Adder::LadnerFischer] {
+                let digest = sha256(&sks, &ct, adder)
+                    .iter()
+                    .map(|word| format!("{:08x}", cks.as_ref().decrypt_radix::<u32>(word)))
+                    .collect::<Vec<_>>()
+                    .concat();
+                assert_eq!(
+                    digest, expected,
+                    "Invalid digest of {str:?} padded with {padding} chars using {adder:?} in \
+                     {mode:?} mode"
+                );
+            }
+        }
+
+        let digest = sha256_radix(&sks, &ct)
+            .iter()
+            .map(|word| format!("{:08x}", cks.as_ref().decrypt_radix::<u32>(word)))
+            .collect::<Vec<_>>()
+            .concat();
+        assert_eq!(
+            digest, expected,
+            "Invalid radix digest of {str:?} padded with {padding} chars"
+        );
+    }
+}
+
+#[test]
+fn string_sha256_encrypted() {
+    let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    // The end of the message and the length written by the padding are encrypted. As the full
+    // circuit is slow on encrypted bits, only one adder runs on them here: the other one is
+    // tested on encrypted words in the unit tests of the bitwise operations, and on the whole
+    // hash of trivial strings in string_sha256.
+    let ct = cks.encrypt_ascii("abc", Some(1));
+    let expected = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+    let digest = sha256(&sks, &ct, Adder::BrentKung)
+        .iter()
+        .map(|word| format!("{:08x}", cks.as_ref().decrypt_radix::<u32>(word)))
+        .collect::<Vec<_>>()
+        .concat();
+    assert_eq!(digest, expected);
+}
+
+#[test]
+fn string_encodings() {
+    let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    // Trivial strings keep the test fast while covering all the digits
+    let cases = [
+        ("", "", ""),
+        ("M", "4d", "TQ=="),
+        ("Ma", "4d61", "TWE="),
+        ("Man", "4d616e", "TWFu"),
+        ("~~~", "7e7e7e", "fn5+"),
+        ("?>?", "3f3e3f", "Pz4/"),
+        ("tfhe-rs", "746668652d7273", "dGZoZS1ycw=="),
+    ];
+
+    for (str, hex, base64) in cases {
+        let ct = sks.create_trivial_ascii(str);
+
+        let ct_res = sks.hex_encode(&ct);
+        assert_eq!(cks.decrypt_ascii(&ct_res), hex, "Invalid hex of {str:?}");
+        let ct_res = sks.base64_encode(&ct);
+        assert_eq!(
+            cks.decrypt_ascii(&ct_res),
+            base64,
+            "Invalid base64 of {str:?}"
+        );
+
+        let (ct_res, is_valid) = sks.hex_decode(&sks.create_trivial_ascii(hex));
+        assert!(cks.decrypt_bool(&is_valid), "{hex:?} should be valid");
+        assert_eq!(cks.decrypt_ascii(&ct_res), str);
+        let (ct_res, is_valid) = sks.hex_decode(&sks.create_trivial_ascii(&hex.to_uppercase()));
+        assert!(cks.decrypt_bool(&is_valid), "{hex:?} should be valid");
+        assert_eq!(cks.decrypt_ascii(&ct_res), str);
+        let (ct_res, is_valid) = sks.base64_decode(&sks.create_trivial_ascii(base64));
+        assert!(cks.decrypt_bool(&is_valid), "{base64:?} should be valid");
+        assert_eq!(cks.decrypt_ascii(&ct_res), str);
+    }
+
+    // Odd length, not a digit, null and non ASCII characters
+    for hex in ["4d6", "4g", "00", "4d00", "80"] {
+        let (ct_res, is_valid) = sks.hex_decode(&sks.create_trivial_ascii(hex));
+        assert!(!cks.decrypt_bool(&is_valid), "{hex:?} should be invalid");
+        assert_eq!(cks.decrypt_ascii(&ct_res), "");
+    }
+    // Invalid length, misplaced '=', not a digit, null and non ASCII characters
+    for base64 in ["TQ=", "T===", "TQ==TQ==", "TQ=a", "TQ@=", "AAAA", "/w=="] {
+        let (ct_res, is_valid) = sks.base64_decode(&sks.create_trivial_ascii(base64));
+        assert!(!cks.decrypt_bool(&is_valid), "{base64:?} should be invalid");
+        assert_eq!(cks.decrypt_ascii(&ct_res), "");
+    }
+
+    // Encrypted padded strings
+    let ct = cks.encrypt_ascii("Ma", Some(2));
+    let ct_res = sks.hex_encode(&ct);
+    assert_eq!(ct_res.len(), 8);
+    assert_eq!(cks.decrypt_ascii(&ct_res), "4d61");
+    let ct_res = sks.base64_encode(&ct);
+    assert_eq!(ct_res.len(), 8);
+    assert_eq!(cks.decrypt_ascii(&ct_res), "TWE=");
+
+    let (ct_res, is_valid) = sks.hex_decode(&cks.encrypt_ascii("4d61", Some(2)));
+    assert!(cks.decrypt_bool(&is_valid));
+    assert_eq!(cks.decrypt_ascii(&ct_res), "Ma");
+    let (ct_res, is_valid) = sks.base64_decode(&cks.encrypt_ascii("TWE=", Some(4)));
+    assert!(cks.decrypt_bool(&is_valid));
+    assert_eq!(cks.decrypt_ascii(&ct_res), "Ma");
+    let (ct_res, is_valid) = sks.base64_decode(&cks.encrypt_ascii("TWE", Some(1)));
+    assert!(!cks.decrypt_bool(&is_valid));
+    assert_eq!(cks.decrypt_ascii(&ct_res), "");
+}
+
+#[test]
+fn string_lookup() {
+    let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    // Public and encrypted keys can be mixed
+    let mut map = FheStringMap::new(4);
+    map.insert(
+        sks.create_trivial_ascii("alice"),
+        cks.as_ref().encrypt_radix(12u8, 4),
+    );
+    map.insert(
+        cks.encrypt_ascii("bob", Some(1)),
+        cks.as_ref().encrypt_radix(34u8, 4),
+    );
+    assert_eq!(map.len(), 2);
+
+    let cases = [
+        ("bob", Some(2), Some(34)),
+        ("alice", None, Some(12)),
+        ("bo", Some(1), None),
+        ("carol", None, None),
+    ];
+
+    for (query, padding, expected) in cases {
+        let ct_query = cks.encrypt_ascii(query, padding);
+
+        let (ct_res, found) = sks.lookup(&map, &ct_query);
+        let value: u8 = cks.as_ref().decrypt_radix(&ct_res);
+        assert_eq!(
+            cks.decrypt_bool(&found),
+            expected.is_some(),
+            "Invalid found flag for {query:?}"
+        );
+        assert_eq!(value, expected.unwrap_or(0), "Invalid value for {query:?}");
+    }
+
+    let (ct_res, found) = sks.lookup(&FheStringMap::new(4), &cks.encrypt_ascii("bob", None));
+    assert!(!cks.decrypt_bool(&found));
+    assert_eq!(cks.as_ref().decrypt_radix::<u8>(&ct_res), 0);
+}
+
+#[test]
+fn string_sort() {
+    let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let decrypt_all = |strs: &[FheString]| -> Vec<String> {
+        strs.iter().map(|str| cks.decrypt_ascii(str)).collect()
+    };
+
+    assert!(sks.sort(&[]).is_empty());
+
+    // Trivial strings, in optimized mode the network is applied without encrypted comparisons
+    let optimized_sks = ServerKey::new_with_mode(sks.integer_key(), ExecutionMode::Optimized);
+    let clear = ["tfhe", "", "fhe", "rs", "tfhe-rs", "a", "fhe"];
+    let strs: Vec<_> = clear
+        .iter()
+        .map(|str| sks.create_trivial_ascii(str))
+        .collect();
+    for len in 1..=clear.len() {
+        let mut expected = clear[..len].to_vec();
+        expected.sort_unstable();
+        let sorted = optimized_sks.sort(&strs[..len]);
+        assert_eq!(decrypt_all(&sorted), expected);
+    }
+
+    // In constant time mode, the comparators of trivial strings go through the homomorphic
+    // selection
+    let sorted = sks.sort(&strs[..3]);
+    assert_eq!(decrypt_all(&sorted), ["", "fhe", "tfhe"]);
+
+    let strs = [
+        cks.encrypt_ascii("bob", Some(1)),
+        cks.encrypt_ascii("alice", None),
+        sks.create_trivial_ascii("bo"),
+    ];
+    let sorted = sks.sort(&strs);
+    assert!(sorted.iter().all(FheString::is_padded));
+    assert!(sorted.iter().all(|str| str.len() == 5));
+    assert_eq!(decrypt_all(&sorted), ["alice", "bo", "bob"]);
+
+    // Sort by the reversed strings
+    let reverse = |str: &FheString| {
+        let len = str.len() - usize::from(str.is_padded());
+        let chars = str.chars()[..len].iter().rev().cloned().collect();
+        FheString::new(chars, false)
+    };
+    let strs = [
+        cks.encrypt_ascii("ab", None),
+        cks.encrypt_ascii("ba", None),
+        cks.encrypt_ascii("ca", None),
+    ];
+    let sorted = sks.sort_by_key(&strs, reverse);
+    let sorted = decrypt_all(&sorted);
+    assert_eq!(sorted[2], "ab");
+    assert!(sorted[..2] == ["ba", "ca"] || sorted[..2] == ["ca", "ba"]);
+}
+
+#[test]
+fn string_pad() {
+    let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let encrypt_width = |width: u8| cks.as_ref().encrypt_radix(width, 2);
+    let fill = cks.encrypt_char(b'*');
+
+    // The last padded string is longer than the maximal width
+    for (str, padding) in [
+        ("ab", Some(1)),
+        ("ab", None),
+        ("", Some(1)),
+        ("ab", Some(4)),
+    ] {
+        let ct = cks.encrypt_ascii(str, padding);
+
+        for width in [0u8, 2, 4, 9] {
+            let ct_width = encrypt_width(width);
+
+            let ct_res = sks.pad_start(&ct, &ct_width, &fill, 4);
+            assert_eq!(ct_res.len(), ct.len().max(4));
+            assert_eq!(
+                cks.decrypt_ascii(&ct_res),
+                format!("{str:*>width$}", width = width.min(4) as usize),
+                "Invalid pad_start of {str:?} to {width}"
+            );
+
+            let ct_res = sks.pad_end(&ct, &ct_width, &fill, 4);
+            assert_eq!(ct_res.len(), ct.len().max(4));
+            assert_eq!(
+                cks.decrypt_ascii(&ct_res),
+                format!("{str:*<width$}", width = width.min(4) as usize),
+                "Invalid pad_end of {str:?} to {width}"
+            );
+        }
+    }
+
+    // Strings at least as long as the maximal width are unchanged
+    let ct = cks.encrypt_ascii("tfhe", None);
+    let ct_res = sks.pad_start(&ct, &encrypt_width(6), &fill, 3);
+    assert_eq!(cks.decrypt_ascii(&ct_res), "tfhe");
+
+    // In optimized mode, a trivial width pads an unpadded string for free
+    let sks = ServerKey::new_with_mode(sks.integer_key(), ExecutionMode::Optimized);
+    let trivial_width = sks.integer_key().create_trivial_radix(3u8, 2);
+
+    let ct_res = sks.pad_start(&cks.encrypt_ascii("a", None), &trivial_width, &fill, 4);
+    assert_eq!(ct_res.len(), 4);
+    assert_eq!(cks.decrypt_ascii(&ct_res), "**a");
+}
+
+#[test]
+fn string_tokenize() {
+    let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let vocabulary = ["bob", "hi", "b", "a,b"];
+    let decrypt_tokens = |tokens: &[(RadixCiphertext, BooleanBlock)]| -> Vec<(u64, bool)> {
+        tokens
+            .iter()
+            .map(|(id, is_present)| (cks.as_ref().decrypt_radix(id), cks.decrypt_bool(is_present)))
+            .collect()
+    };
+
+    // "bo" is out of the vocabulary, and "a,b" cannot match as it contains a separator
+    let ct = cks.encrypt_ascii("bo b!hi", Some(1));
+    let tokens = sks.tokenize(&ct, &vocabulary, 4);
+    assert_eq!(
+        decrypt_tokens(&tokens),
+        [(4, true), (2, true), (1, true), (0, false)]
+    );
+
+    // Without vocabulary, all the tokens are unknown, and the extra ones are dropped
+    let ct = cks.encrypt_ascii(" a b", None);
+    let tokens = sks.tokenize(&ct, &[], 1);
+    assert_eq!(decrypt_tokens(&tokens), [(0, true)]);
+}
+
+#[test]
+fn string_pattern() {
+    let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let ct = cks.encrypt_ascii("abab", Some(1));
+
+    for (pattern, padding) in [("", Some(1)), ("ba", None), ("ab", Some(2)), ("bb", None)] {
+        let ct_pattern = cks.encrypt_ascii(pattern, padding);
+
+        let ct_res = sks.starts_with(&ct, &ct_pattern);
+        assert_eq!(cks.decrypt_bool(&ct_res), "abab".starts_with(pattern));
+
+        let (index, found) = sks.find(&ct, &ct_pattern);
+        let expected = "abab".find(pattern);
+        assert_eq!(cks.decrypt_bool(&found), expected.is_some());
+        let index: u64 = cks.as_ref().decrypt_radix(&index);
+        assert_eq!(
+            index,
+            expected.unwrap_or(0) as u64,
+            "Invalid find of {pattern:?}"
+        );
+    }
+
+    // Patterns longer than the string
+    let ct_pattern = cks.encrypt_ascii("ababab", None);
+    let ct_res = sks.contains(&ct, &ct_pattern);
+    assert!(!cks.decrypt_bool(&ct_res));
+    assert!(matches!(
+        sks.starts_with(&ct, &ct_pattern).decrypt_trivial(),
+        Ok(false)
+    ));
+}